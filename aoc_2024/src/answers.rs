@@ -0,0 +1,349 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use crate::diagnostics;
+use crate::error::AocError;
+use crate::inputs::YEAR;
+use crate::solution::{Day, Part, PartAnswer};
+use crate::toml::{Document, Table, Value};
+
+/// Environment variable that overrides where the answers ledger lives.
+pub const ANSWERS_DIR_VAR: &str = "AOC_ANSWERS_DIR";
+
+const DEFAULT_ANSWERS_DIR: &str = "answers";
+
+/// What the website said about a submitted answer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about the direction.
+    Wrong,
+}
+
+impl Verdict {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+        }
+    }
+
+    fn from_str(value: &str) -> Option<Verdict> {
+        match value {
+            "correct" => Some(Verdict::Correct),
+            "too high" => Some(Verdict::TooHigh),
+            "too low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Everything known about the answer to one part.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PartRecord {
+    pub correct: Option<String>,
+    /// Answers that were submitted and rejected.
+    pub wrong: BTreeMap<String, Verdict>,
+}
+
+impl PartRecord {
+    /// What is already known about an answer without submitting it. Besides the recorded guesses,
+    /// a numeric answer beyond a guess that was too high or too low must be wrong too.
+    pub fn known_verdict(&self, answer: &str) -> Option<Verdict> {
+        if let Some(correct) = &self.correct {
+            return Some(if correct == answer {
+                Verdict::Correct
+            } else {
+                Verdict::Wrong
+            });
+        }
+        if let Some(verdict) = self.wrong.get(answer) {
+            return Some(*verdict);
+        }
+
+        let number: i64 = answer.parse().ok()?;
+        self.wrong.iter().find_map(|(guess, verdict)| {
+            let guess: i64 = guess.parse().ok()?;
+            match verdict {
+                Verdict::TooHigh if number >= guess => Some(Verdict::TooHigh),
+                Verdict::TooLow if number <= guess => Some(Verdict::TooLow),
+                _ => None,
+            }
+        })
+    }
+}
+
+/// Known answers per day and part, stored as `<dir>/<year>.toml`:
+///
+/// ```toml
+/// [day_06]
+/// part_1 = "41"
+/// part_2_guess_1700 = "too high"
+/// "part_2_guess_1,7" = "wrong"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ledger {
+    pub records: BTreeMap<(u32, Part), PartRecord>,
+}
+
+fn section_name(day: u32) -> String {
+    format!("day_{:02}", day)
+}
+
+fn part_key(part: Part) -> String {
+    format!("part_{}", part)
+}
+
+impl Ledger {
+    /// `<year>.toml` under `$AOC_ANSWERS_DIR`, which defaults to `answers`.
+    pub fn path_from_env() -> PathBuf {
+        crate::env_or_crate_path(ANSWERS_DIR_VAR, DEFAULT_ANSWERS_DIR)
+            .join(format!("{}.toml", YEAR))
+    }
+
+    pub fn from_document(document: &Document) -> Result<Ledger, AocError> {
+        let mut ledger = Ledger::default();
+
+        for (section, table) in &document.sections {
+            let day: u32 = section
+                .strip_prefix("day_")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| AocError::Config(format!("Unexpected section [{}]", section)))?;
+
+            for (key, value) in table {
+                let value = value.as_str().ok_or_else(|| {
+                    AocError::Config(format!("Expected '{}' to be a string", key))
+                })?;
+                let (part, guess) = match key.split_once("_guess_") {
+                    Some((part, guess)) => (part, Some(guess)),
+                    None => (key.as_str(), None),
+                };
+                let part = match part {
+                    "part_1" => Part::One,
+                    "part_2" => Part::Two,
+                    _ => return Err(AocError::Config(format!("Unexpected key '{}'", key))),
+                };
+
+                let record = ledger.records.entry((day, part)).or_default();
+                match guess {
+                    Some(guess) => {
+                        let verdict = Verdict::from_str(value).ok_or_else(|| {
+                            AocError::Config(format!("Unexpected verdict '{}'", value))
+                        })?;
+                        record.wrong.insert(guess.to_string(), verdict);
+                    }
+                    None => record.correct = Some(value.to_string()),
+                }
+            }
+        }
+
+        Ok(ledger)
+    }
+
+    pub fn to_document(&self) -> Document {
+        let mut document = Document::default();
+        for ((day, part), record) in &self.records {
+            let table: &mut Table = document.sections.entry(section_name(*day)).or_default();
+            if let Some(correct) = &record.correct {
+                table.insert(part_key(*part), Value::String(correct.clone()));
+            }
+            for (guess, verdict) in &record.wrong {
+                table.insert(
+                    format!("{}_guess_{}", part_key(*part), guess),
+                    Value::String(verdict.to_string()),
+                );
+            }
+        }
+        document
+    }
+
+    /// An empty ledger if there is no file yet.
+    pub fn load(path: &Path) -> Result<Ledger, AocError> {
+        crate::load_or_default(path, |text| Ledger::from_document(&Document::parse(text)?))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        crate::save(path, &self.to_document().to_string())
+    }
+
+    pub fn record(&self, day: u32, part: Part) -> Option<&PartRecord> {
+        self.records.get(&(day, part))
+    }
+
+    pub fn correct_answer(&self, day: u32, part: Part) -> Option<&str> {
+        self.record(day, part)?.correct.as_deref()
+    }
+
+    pub fn known_verdict(&self, day: u32, part: Part, answer: &str) -> Option<Verdict> {
+        self.record(day, part)?.known_verdict(answer)
+    }
+
+    pub fn add(&mut self, day: u32, part: Part, answer: &str, verdict: Verdict) {
+        let record = self.records.entry((day, part)).or_default();
+        match verdict {
+            Verdict::Correct => record.correct = Some(answer.to_string()),
+            verdict => {
+                record.wrong.insert(answer.to_string(), verdict);
+            }
+        }
+    }
+}
+
+/// Outcome of comparing a computed answer with the ledger.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Check {
+    Matches(String),
+    Differs {
+        expected: String,
+        actual: String,
+    },
+    /// The ledger has no correct answer for the part yet.
+    Unknown(String),
+    Unsolved,
+}
+
+impl Check {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Check::Differs { .. })
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Check::Matches(answer) => write!(f, "ok ({})", answer),
+            Check::Differs { expected, actual } => {
+                write!(f, "MISMATCH: expected {}, got {}", expected, actual)
+            }
+            Check::Unknown(answer) => write!(f, "no known answer (got {})", answer),
+            Check::Unsolved => write!(f, "not solved yet"),
+        }
+    }
+}
+
+pub fn check(ledger: &Ledger, day: u32, answer: &PartAnswer) -> Check {
+    let Some(actual) = &answer.answer else {
+        return Check::Unsolved;
+    };
+    match ledger.correct_answer(day, answer.part) {
+        Some(expected) if expected == actual => Check::Matches(actual.clone()),
+        Some(expected) => Check::Differs {
+            expected: expected.to_string(),
+            actual: actual.clone(),
+        },
+        None => Check::Unknown(actual.clone()),
+    }
+}
+
+/// Solve both parts of a day and compare them with the ledger.
+pub fn verify(ledger: &Ledger, day: &Day, raw_input: &str) -> Result<Vec<(Part, Check)>, AocError> {
+    Ok(
+        diagnostics::discard(|| (day.solve)(raw_input, &Part::BOTH))?
+            .answers
+            .iter()
+            .map(|answer| (answer.part, check(ledger, day.number, answer)))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ledger_with_guesses() -> Ledger {
+        let mut ledger = Ledger::default();
+        ledger.add(6, Part::One, "41", Verdict::Correct);
+        ledger.add(6, Part::Two, "1700", Verdict::TooHigh);
+        ledger.add(6, Part::Two, "1200", Verdict::TooLow);
+        ledger.add(6, Part::Two, "1500", Verdict::Wrong);
+        ledger
+    }
+
+    #[test]
+    fn ledger_round_trips_through_toml() {
+        let ledger = ledger_with_guesses();
+        let text = ledger.to_document().to_string();
+
+        assert!(text.contains("[day_06]\npart_1 = \"41\"\n"));
+        assert!(text.contains("part_2_guess_1700 = \"too high\"\n"));
+        let parsed = Ledger::from_document(&Document::parse(&text).unwrap()).unwrap();
+        assert_eq!(parsed, ledger);
+    }
+
+    #[test]
+    fn ledger_round_trips_guesses_with_any_text() {
+        let mut ledger = Ledger::default();
+        ledger.add(17, Part::One, "4,6,3,5,6,3,5,2,1,0", Verdict::Correct);
+        ledger.add(17, Part::Two, "a = b", Verdict::Wrong);
+        ledger.add(17, Part::Two, "1,2 3", Verdict::Wrong);
+        ledger.add(17, Part::Two, "\"quoted\" # not a comment", Verdict::Wrong);
+        let path = crate::test_dir("ledger_any_text").join("2024.toml");
+
+        ledger.save(&path).unwrap();
+        assert_eq!(Ledger::load(&path).unwrap(), ledger);
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(text.contains("\"part_2_guess_a = b\" = \"wrong\"\n"));
+    }
+
+    #[test]
+    fn known_verdict_infers_from_bounds() {
+        let ledger = ledger_with_guesses();
+
+        assert_eq!(
+            ledger.known_verdict(6, Part::One, "41"),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            ledger.known_verdict(6, Part::One, "42"),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            ledger.known_verdict(6, Part::Two, "1500"),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            ledger.known_verdict(6, Part::Two, "1800"),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            ledger.known_verdict(6, Part::Two, "1000"),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(ledger.known_verdict(6, Part::Two, "1400"), None);
+        assert_eq!(ledger.known_verdict(7, Part::One, "1"), None);
+    }
+
+    #[test]
+    fn check_compares_with_correct_answer() {
+        let ledger = ledger_with_guesses();
+        let answer = |part, answer: Option<&str>| PartAnswer {
+            part,
+            answer: answer.map(str::to_string),
+            duration: std::time::Duration::ZERO,
+            diagnostics: Vec::new(),
+        };
+
+        assert_eq!(
+            check(&ledger, 6, &answer(Part::One, Some("41"))),
+            Check::Matches("41".to_string())
+        );
+        assert!(check(&ledger, 6, &answer(Part::One, Some("40"))).is_failure());
+        assert_eq!(
+            check(&ledger, 6, &answer(Part::Two, Some("1400"))),
+            Check::Unknown("1400".to_string())
+        );
+        assert_eq!(check(&ledger, 6, &answer(Part::Two, None)), Check::Unsolved);
+    }
+}
@@ -0,0 +1,772 @@
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::direction::Direction;
+use crate::error::AocError;
+use crate::vec2::Vec2;
+
+/// A grid of values stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Array2D<T> {
+    pub height: usize,
+    pub width: usize,
+    data: Vec<T>,
+    boundary: Boundary<T>,
+}
+
+/// What the grid does with positions outside of it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Boundary<T> {
+    /// Reading or writing outside the grid is an `OutOfBounds` error.
+    Error,
+    /// The grid repeats in every direction, so leaving it on one side comes back on the other.
+    Wrap,
+    /// Reading outside the grid gives the nearest cell on its edge. Writing there is still an
+    /// error, so the edge is not overwritten by accident.
+    Clamp,
+    /// Reading outside the grid gives the value. Writing there is still an error.
+    Default(T),
+}
+
+impl<T: Clone> Array2D<T> {
+    pub fn new(height: usize, width: usize, init_value: T) -> Array2D<T> {
+        let size: usize = height.checked_mul(width).unwrap();
+
+        let data: Vec<T> = std::iter::repeat_n(init_value, size).collect();
+        Array2D {
+            height,
+            width,
+            data,
+            boundary: Boundary::Error,
+        }
+    }
+
+    /// A clone of the value at the position. See `get_ref` to borrow it instead.
+    pub fn get(&self, pos: Vec2) -> Result<T, AocError> {
+        self.get_ref(pos).cloned()
+    }
+
+    /// Set every cell to a clone of the value.
+    pub fn fill(&mut self, value: T) {
+        self.data.fill(value);
+    }
+}
+
+impl<T> Array2D<T> {
+    /// A grid with the value of each cell computed from its row and column, for element types
+    /// that cannot be cloned.
+    pub fn from_fn(
+        height: usize,
+        width: usize,
+        mut f: impl FnMut(usize, usize) -> T,
+    ) -> Array2D<T> {
+        let size: usize = height.checked_mul(width).unwrap();
+
+        let data: Vec<T> = (0..size).map(|idx| f(idx / width, idx % width)).collect();
+        Array2D {
+            height,
+            width,
+            data,
+            boundary: Boundary::Error,
+        }
+    }
+
+    /// The same grid, treating positions outside of it as the boundary says. Grids start out
+    /// with `Boundary::Error`.
+    pub fn with_boundary(mut self, boundary: Boundary<T>) -> Array2D<T> {
+        self.boundary = boundary;
+        self
+    }
+
+    pub fn boundary(&self) -> &Boundary<T> {
+        &self.boundary
+    }
+
+    fn idx1d(&self, pos: Vec2) -> Result<usize, AocError> {
+        match self.resolve(pos) {
+            Some(pos) => {
+                let idx: usize = (pos.y * self.width as i64 + pos.x).try_into().unwrap();
+                Ok(idx)
+            }
+            None => Err(AocError::OutOfBounds {
+                index: vec![pos.y, pos.x],
+                shape: vec![self.height, self.width],
+            }),
+        }
+    }
+
+    /// Like `idx1d`, but only `Wrap` lets a position outside the grid be written to.
+    fn idx1d_mut(&self, pos: Vec2) -> Result<usize, AocError> {
+        if matches!(self.boundary, Boundary::Clamp) && !self.in_bounds(pos) {
+            return Err(AocError::OutOfBounds {
+                index: vec![pos.y, pos.x],
+                shape: vec![self.height, self.width],
+            });
+        }
+        self.idx1d(pos)
+    }
+
+    pub fn in_bounds(&self, pos: Vec2) -> bool {
+        pos.y >= 0 && pos.y < self.height as i64 && pos.x >= 0 && pos.x < self.width as i64
+    }
+
+    /// The position inside the grid that `pos` stands for under the boundary, if there is one.
+    /// Only `Wrap` and `Clamp` map positions outside the grid to a cell.
+    pub fn resolve(&self, pos: Vec2) -> Option<Vec2> {
+        if self.in_bounds(pos) {
+            return Some(pos);
+        }
+        if self.height == 0 || self.width == 0 {
+            return None;
+        }
+        let (height, width) = (self.height as i64, self.width as i64);
+        match self.boundary {
+            Boundary::Wrap => Some(Vec2::new(pos.x.rem_euclid(width), pos.y.rem_euclid(height))),
+            Boundary::Clamp => Some(Vec2::new(
+                pos.x.clamp(0, width - 1),
+                pos.y.clamp(0, height - 1),
+            )),
+            Boundary::Error | Boundary::Default(_) => None,
+        }
+    }
+
+    /// The positions one step away in the directions that are inside the grid, or that wrap
+    /// around into it if the boundary is `Wrap`.
+    pub(crate) fn neighbors<'a>(
+        &'a self,
+        pos: Vec2,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = Vec2> + 'a {
+        directions
+            .iter()
+            .map(move |dir| dir.step(pos))
+            .filter_map(|neighbor| match self.boundary {
+                Boundary::Wrap => self.resolve(neighbor),
+                _ => Some(neighbor).filter(|&neighbor| self.in_bounds(neighbor)),
+            })
+    }
+
+    /// The positions above, right of, below and left of `pos` that are inside the grid, or that
+    /// wrap around into it.
+    pub fn neighbors4(&self, pos: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        self.neighbors(pos, &Direction::CARDINAL)
+    }
+
+    /// Like `neighbors4`, but including the diagonal neighbors.
+    pub fn neighbors8(&self, pos: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        self.neighbors(pos, &Direction::ALL)
+    }
+
+    /// The positions from `pos` in the direction until the edge of the grid, not including
+    /// `pos` itself.
+    pub fn ray(&self, pos: Vec2, dir: Direction) -> impl Iterator<Item = Vec2> + '_ {
+        std::iter::successors(Some(dir.step(pos)), move |&pos| Some(dir.step(pos)))
+            .take_while(|&pos| self.in_bounds(pos))
+    }
+
+    /// Set the value at the position. Outside the grid this is an error unless the boundary
+    /// is `Wrap`.
+    pub fn set(&mut self, pos: Vec2, value: T) -> Result<(), AocError> {
+        let idx = self.idx1d_mut(pos)?;
+        self.data[idx] = value;
+        Ok(())
+    }
+
+    pub fn get_ref(&self, pos: Vec2) -> Result<&T, AocError> {
+        match (self.idx1d(pos), &self.boundary) {
+            (Ok(idx), _) => Ok(&self.data[idx]),
+            (Err(_), Boundary::Default(value)) => Ok(value),
+            (Err(err), _) => Err(err),
+        }
+    }
+
+    /// Like `set`, outside the grid this is an error unless the boundary is `Wrap`.
+    pub fn get_mut(&mut self, pos: Vec2) -> Result<&mut T, AocError> {
+        let idx = self.idx1d_mut(pos)?;
+        Ok(&mut self.data[idx])
+    }
+}
+
+impl<T> Array2D<T> {
+    /// The rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // A grid without columns still has its rows
+        (0..self.height).map(move |row| &self.data[row * self.width..(row + 1) * self.width])
+    }
+
+    /// The values in a column from top to bottom. Panics if the column is outside the grid.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "Column {} is outside the grid", col);
+        self.data.iter().skip(col).step_by(self.width)
+    }
+
+    /// The columns from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// The values along a line from `start` in the direction, including `start` itself.
+    fn line(&self, start: Vec2, dir: Direction) -> impl Iterator<Item = &T> {
+        std::iter::once(start)
+            .chain(self.ray(start, dir))
+            .map(move |pos| &self[pos])
+    }
+
+    /// The height and width, or no rows and columns at all if the grid has no cells.
+    fn nonempty_shape(&self) -> (usize, usize) {
+        if self.data.is_empty() {
+            (0, 0)
+        } else {
+            (self.height, self.width)
+        }
+    }
+
+    /// The diagonals running down and to the right, from the one that starts in the top right
+    /// corner to the one that starts in the bottom left corner. A grid without cells has none.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (height, width) = self.nonempty_shape();
+        let top = (0..width).rev().map(|col| Vec2::from_index(0, col));
+        let left = (1..height).map(|row| Vec2::from_index(row, 0));
+        top.chain(left)
+            .map(move |start| self.line(start, Direction::DownRight))
+    }
+
+    /// The diagonals running down and to the left, from the one that starts in the top left
+    /// corner to the one that starts in the bottom right corner. A grid without cells has none.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (height, width) = self.nonempty_shape();
+        let top = (0..width).map(|col| Vec2::from_index(0, col));
+        let right = (1..height).map(move |row| Vec2::from_index(row, width - 1));
+        top.chain(right)
+            .map(move |start| self.line(start, Direction::DownLeft))
+    }
+
+    /// Every position with its value, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2, &T)> {
+        let width = self.width;
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(idx, value)| (Vec2::from_index(idx / width, idx % width), value))
+    }
+
+    /// Like `iter`, but the values can be changed.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Vec2, &mut T)> {
+        let width = self.width;
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(move |(idx, value)| (Vec2::from_index(idx / width, idx % width), value))
+    }
+
+    /// A borrowed rectangle of the grid with its top left corner at `top_left`.
+    pub fn view(
+        &self,
+        top_left: Vec2,
+        height: usize,
+        width: usize,
+    ) -> Result<View<'_, T>, AocError> {
+        let bottom_right = top_left + Vec2::new(width as i64 - 1, height as i64 - 1);
+        if height > 0 && width > 0 {
+            if let Some(corner) = [top_left, bottom_right]
+                .into_iter()
+                .find(|&corner| !self.in_bounds(corner))
+            {
+                return Err(AocError::OutOfBounds {
+                    index: vec![corner.y, corner.x],
+                    shape: vec![self.height, self.width],
+                });
+            }
+        }
+        Ok(View {
+            array: self,
+            top_left,
+            height,
+            width,
+        })
+    }
+}
+
+/// A rectangle of an `Array2D`, with positions relative to its top left corner.
+#[derive(Copy, Clone, Debug)]
+pub struct View<'a, T> {
+    array: &'a Array2D<T>,
+    top_left: Vec2,
+    pub height: usize,
+    pub width: usize,
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn get_ref(&self, pos: Vec2) -> Result<&'a T, AocError> {
+        if pos.y < 0 || pos.y >= self.height as i64 || pos.x < 0 || pos.x >= self.width as i64 {
+            return Err(AocError::OutOfBounds {
+                index: vec![pos.y, pos.x],
+                shape: vec![self.height, self.width],
+            });
+        }
+        self.array.get_ref(self.top_left + pos)
+    }
+
+    /// The rows of the view from top to bottom. A view without columns has empty rows, wherever
+    /// it was placed.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + use<'a, T> {
+        let (array, top_left, width) = (self.array, self.top_left, self.width);
+        (0..self.height).map(move |row| {
+            if width == 0 {
+                return &array.data[..0];
+            }
+            let start = (top_left.y as usize + row) * array.width + top_left.x as usize;
+            &array.data[start..start + width]
+        })
+    }
+
+    /// Every position in the view with its value, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2, &'a T)> + use<'a, T> {
+        self.rows().enumerate().flat_map(|(row, values)| {
+            values
+                .iter()
+                .enumerate()
+                .map(move |(col, value)| (Vec2::from_index(row, col), value))
+        })
+    }
+}
+
+impl<T: Clone> View<'_, T> {
+    /// A copy of the view as a grid of its own.
+    pub fn to_array(&self) -> Array2D<T> {
+        Array2D {
+            height: self.height,
+            width: self.width,
+            data: self.rows().flatten().cloned().collect(),
+            boundary: Boundary::Error,
+        }
+    }
+}
+
+impl<T> Array2D<T> {
+    /// Parse a grid with a line of text per row, mapping each character to a value. A message
+    /// returned by `f` becomes a parse error at the character. Lines may end in `\r\n`, and all
+    /// lines must be equally long.
+    pub fn parse_with(
+        raw_input: &str,
+        mut f: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Array2D<T>, AocError> {
+        let lines: Vec<&str> = raw_input.trim_end_matches(['\r', '\n']).lines().collect();
+        if lines.is_empty() {
+            return Err(AocError::InvalidInput("Input is empty".to_string()));
+        }
+        // An empty first line is reported as ragged against the first line with values
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .find(|&width| width > 0)
+            .unwrap_or(0);
+
+        let mut data = Vec::with_capacity(lines.len() * width);
+        for (line_index, line) in lines.iter().enumerate() {
+            let line_width = line.chars().count();
+            if line_width != width {
+                return Err(AocError::parse(
+                    line_index,
+                    line_width.min(width) + 1,
+                    format!("Expected a line of width {}, found {}", width, line_width),
+                ));
+            }
+            for (col, ch) in line.chars().enumerate() {
+                data.push(f(ch).map_err(|message| AocError::parse(line_index, col + 1, message))?);
+            }
+        }
+
+        Ok(Array2D {
+            height: lines.len(),
+            width,
+            data,
+            boundary: Boundary::Error,
+        })
+    }
+}
+
+/// Transformations that build a new grid. The new grid keeps the boundary of the old one.
+impl<T: Clone> Array2D<T> {
+    /// A grid of the given shape with each cell cloned from the cell of this grid at the
+    /// (row, column) that `source` gives for it.
+    fn remapped(
+        &self,
+        height: usize,
+        width: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Array2D<T> {
+        Array2D::from_fn(height, width, |row, col| {
+            let (row, col) = source(row, col);
+            self.data[row * self.width + col].clone()
+        })
+        .with_boundary(self.boundary.clone())
+    }
+
+    /// Turned a quarter turn clockwise.
+    pub fn rotate_90(&self) -> Array2D<T> {
+        let height = self.height;
+        self.remapped(self.width, self.height, |row, col| (height - 1 - col, row))
+    }
+
+    pub fn rotate_180(&self) -> Array2D<T> {
+        let (height, width) = (self.height, self.width);
+        self.remapped(height, width, |row, col| {
+            (height - 1 - row, width - 1 - col)
+        })
+    }
+
+    /// Turned a quarter turn counterclockwise.
+    pub fn rotate_270(&self) -> Array2D<T> {
+        let width = self.width;
+        self.remapped(self.width, self.height, |row, col| (col, width - 1 - row))
+    }
+
+    /// Mirrored in the diagonal from the top left corner, so rows become columns.
+    pub fn transpose(&self) -> Array2D<T> {
+        self.remapped(self.width, self.height, |row, col| (col, row))
+    }
+
+    /// Mirrored left to right.
+    pub fn flip_horizontal(&self) -> Array2D<T> {
+        let width = self.width;
+        self.remapped(self.height, width, |row, col| (row, width - 1 - col))
+    }
+
+    /// Mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Array2D<T> {
+        let height = self.height;
+        self.remapped(height, self.width, |row, col| (height - 1 - row, col))
+    }
+
+    /// A copy of the rectangle with its top left corner at `top_left`. See `view` to borrow it
+    /// instead.
+    pub fn crop(
+        &self,
+        top_left: Vec2,
+        height: usize,
+        width: usize,
+    ) -> Result<Array2D<T>, AocError> {
+        let view = self.view(top_left, height, width)?;
+        Ok(view.to_array().with_boundary(self.boundary.clone()))
+    }
+
+    /// Surrounded by a border of `value` that is `border` cells thick.
+    pub fn pad(&self, border: usize, value: T) -> Array2D<T> {
+        Array2D::from_fn(
+            self.height + 2 * border,
+            self.width + 2 * border,
+            |row, col| {
+                let (row, col) = (row.wrapping_sub(border), col.wrapping_sub(border));
+                if row < self.height && col < self.width {
+                    self.data[row * self.width + col].clone()
+                } else {
+                    value.clone()
+                }
+            },
+        )
+        .with_boundary(self.boundary.clone())
+    }
+
+    /// Repeated `down` times downwards and `across` times to the right.
+    pub fn tile(&self, down: usize, across: usize) -> Array2D<T> {
+        let (height, width) = (self.height, self.width);
+        self.remapped(height * down, width * across, |row, col| {
+            (row % height, col % width)
+        })
+    }
+}
+
+impl FromStr for Array2D<char> {
+    type Err = AocError;
+
+    fn from_str(raw_input: &str) -> Result<Array2D<char>, AocError> {
+        Array2D::parse_with(raw_input, Ok)
+    }
+}
+
+impl FromStr for Array2D<u8> {
+    type Err = AocError;
+
+    fn from_str(raw_input: &str) -> Result<Array2D<u8>, AocError> {
+        Array2D::parse_with(raw_input, |ch| {
+            u8::try_from(ch)
+                .ok()
+                .filter(u8::is_ascii)
+                .ok_or_else(|| format!("Expected an ASCII character, found '{}'", ch))
+        })
+    }
+}
+
+/// Panics where `get_ref` is an error, so outside the grid only with `Boundary::Error`.
+impl<T> Index<Vec2> for Array2D<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vec2) -> &T {
+        self.get_ref(pos).unwrap()
+    }
+}
+
+/// Panics where `get_mut` is an error, so outside the grid unless the boundary is `Wrap`.
+impl<T> IndexMut<Vec2> for Array2D<T> {
+    fn index_mut(&mut self, pos: Vec2) -> &mut T {
+        self.get_mut(pos).unwrap()
+    }
+}
+
+/// Every position with its value, row by row, like `iter` but taking the values.
+impl<T> IntoIterator for Array2D<T> {
+    type Item = (Vec2, T);
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter {
+            width: self.width,
+            cells: self.data.into_iter().enumerate(),
+        }
+    }
+}
+
+pub struct IntoIter<T> {
+    width: usize,
+    cells: std::iter::Enumerate<std::vec::IntoIter<T>>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = (Vec2, T);
+
+    fn next(&mut self) -> Option<(Vec2, T)> {
+        let (idx, value) = self.cells.next()?;
+        Some((Vec2::from_index(idx / self.width, idx % self.width), value))
+    }
+}
+
+fn write_rows<'a, T: Display + 'a>(
+    f: &mut std::fmt::Formatter,
+    rows: impl Iterator<Item = &'a [T]>,
+) -> std::fmt::Result {
+    for row in rows {
+        for value in row {
+            write!(f, "{}", value)?;
+        }
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
+impl<T: Display> Display for Array2D<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write_rows(f, self.rows())
+    }
+}
+
+impl<T: Display> Display for View<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write_rows(f, self.rows())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_copy_values_can_be_borrowed_and_changed() {
+        let mut array = Array2D::from_fn(2, 3, |row, col| vec![row; col]);
+
+        assert_eq!(array.get_ref(Vec2::new(2, 1)).unwrap(), &vec![1, 1]);
+        array.get_mut(Vec2::new(1, 0)).unwrap().push(7);
+        array[Vec2::new(0, 1)].push(8);
+        assert_eq!(array[Vec2::new(0, 1)], vec![8]);
+        assert_eq!(array.get(Vec2::new(1, 0)).unwrap(), vec![0, 7]);
+        assert!(matches!(
+            array.get_mut(Vec2::new(0, 2)),
+            Err(AocError::OutOfBounds { .. })
+        ));
+
+        array.fill(vec![]);
+        assert_eq!(array, Array2D::new(2, 3, Vec::new()));
+    }
+
+    #[test]
+    fn neighbors_and_rays_stay_inside() {
+        let array = Array2D::new(3, 4, 0);
+
+        assert!(array.in_bounds(Vec2::new(3, 2)));
+        assert!(!array.in_bounds(Vec2::new(0, 3)));
+        assert!(!array.in_bounds(Vec2::new(-1, 0)));
+        assert_eq!(
+            array.neighbors4(Vec2::ZERO).collect::<Vec<_>>(),
+            vec![Vec2::new(1, 0), Vec2::new(0, 1)]
+        );
+        assert_eq!(array.neighbors8(Vec2::new(1, 1)).count(), 8);
+        assert_eq!(array.neighbors8(Vec2::new(3, 2)).count(), 3);
+        assert_eq!(
+            array
+                .ray(Vec2::new(1, 0), Direction::DownRight)
+                .collect::<Vec<_>>(),
+            vec![Vec2::new(2, 1), Vec2::new(3, 2)]
+        );
+        assert_eq!(array.ray(Vec2::new(1, 0), Direction::Up).count(), 0);
+    }
+
+    fn collect<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn rows_columns_and_diagonals() {
+        let array: Array2D<char> = "abc\ndef\n".parse().unwrap();
+
+        assert_eq!(
+            array.rows().collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(collect(array.columns()), ["ad", "be", "cf"]);
+        assert_eq!(collect(array.diagonals()), ["c", "bf", "ae", "d"]);
+        assert_eq!(collect(array.anti_diagonals()), ["a", "bd", "ce", "f"]);
+
+        let empty = Array2D::new(3, 0, 'x');
+        assert_eq!(empty.diagonals().count(), 0);
+        assert_eq!(empty.anti_diagonals().count(), 0);
+        assert_eq!(Array2D::new(0, 3, 'x').anti_diagonals().count(), 0);
+    }
+
+    #[test]
+    fn iter_pairs_positions_with_values() {
+        let mut array: Array2D<char> = "ab\ncd\n".parse().unwrap();
+
+        assert_eq!(
+            array.iter().find(|(_, value)| **value == 'c'),
+            Some((Vec2::new(0, 1), &'c'))
+        );
+        for (pos, value) in array.iter_mut() {
+            if pos.x == 1 {
+                *value = value.to_ascii_uppercase();
+            }
+        }
+        assert_eq!(array.to_string(), "aB\ncD\n");
+    }
+
+    #[test]
+    fn views_borrow_a_rectangle() {
+        let array: Array2D<char> = "abcd\nefgh\nijkl\n".parse().unwrap();
+        let view = array.view(Vec2::new(1, 1), 2, 3).unwrap();
+
+        assert_eq!(view.to_string(), "fgh\njkl\n");
+        assert_eq!(view.get_ref(Vec2::new(0, 1)).unwrap(), &'j');
+        assert!(view.get_ref(Vec2::new(3, 0)).is_err());
+        assert_eq!(view.iter().nth(4), Some((Vec2::new(1, 1), &'k')));
+        assert_eq!(view.to_array().rows().count(), 2);
+        assert!(array.view(Vec2::new(2, 2), 2, 3).is_err());
+        assert!(matches!(
+            array.view(Vec2::new(-1, 0), 2, 2),
+            Err(AocError::OutOfBounds { index, .. }) if index == [0, -1]
+        ));
+
+        let empty = array.view(Vec2::new(5, 5), 2, 0).unwrap();
+        assert_eq!(empty.rows().collect::<Vec<_>>(), [[], []]);
+        assert_eq!(empty.to_string(), "\n\n");
+    }
+
+    #[test]
+    fn boundaries_decide_what_is_outside() {
+        let array: Array2D<char> = "ab\ncd\n".parse().unwrap();
+        assert!(array.get(Vec2::new(0, 2)).is_err());
+
+        let mut wrapping = array.clone().with_boundary(Boundary::Wrap);
+        assert_eq!(wrapping.get(Vec2::new(2, -1)).unwrap(), 'c');
+        assert_eq!(wrapping.resolve(Vec2::new(-3, 5)), Some(Vec2::new(1, 1)));
+        assert_eq!(wrapping.neighbors4(Vec2::ZERO).count(), 4);
+        wrapping.set(Vec2::new(5, 4), 'x').unwrap();
+        assert_eq!(wrapping[Vec2::new(1, 0)], 'x');
+
+        let mut clamped = array.clone().with_boundary(Boundary::Clamp);
+        assert_eq!(clamped.get(Vec2::new(9, -5)).unwrap(), 'b');
+        assert_eq!(clamped.neighbors4(Vec2::ZERO).count(), 2);
+        assert!(clamped.set(Vec2::new(9, -5), 'x').is_err());
+        assert!(clamped.get_mut(Vec2::new(-1, 0)).is_err());
+        assert_eq!(clamped.to_string(), "ab\ncd\n");
+
+        let mut padded = array.with_boundary(Boundary::Default('.'));
+        assert_eq!(padded.get(Vec2::new(-7, 7)).unwrap(), '.');
+        assert_eq!(padded.get(Vec2::new(0, 1)).unwrap(), 'c');
+        assert!(padded.set(Vec2::new(-7, 7), 'x').is_err());
+    }
+
+    #[test]
+    fn transformations_build_new_grids() {
+        let array: Array2D<char> = "abc\ndef\n".parse().unwrap();
+        let grid = |text: &str| text.parse::<Array2D<char>>().unwrap();
+
+        assert_eq!(array.rotate_90(), grid("da\neb\nfc\n"));
+        assert_eq!(array.rotate_180(), grid("fed\ncba\n"));
+        assert_eq!(array.rotate_270(), grid("cf\nbe\nad\n"));
+        assert_eq!(array.rotate_90().rotate_270(), array);
+        assert_eq!(array.transpose(), grid("ad\nbe\ncf\n"));
+        assert_eq!(array.flip_horizontal(), grid("cba\nfed\n"));
+        assert_eq!(array.flip_vertical(), grid("def\nabc\n"));
+        assert_eq!(array.crop(Vec2::new(1, 0), 2, 2).unwrap(), grid("bc\nef\n"));
+        assert!(array.crop(Vec2::new(2, 0), 2, 2).is_err());
+        assert_eq!(array.pad(1, '.'), grid(".....\n.abc.\n.def.\n.....\n"));
+        assert_eq!(array.tile(2, 2), grid("abcabc\ndefdef\nabcabc\ndefdef\n"));
+    }
+
+    #[test]
+    fn parse_handles_line_endings() {
+        let array: Array2D<char> = "ab\r\ncd\r\n".parse().unwrap();
+
+        assert_eq!((array.height, array.width), (2, 2));
+        assert_eq!(array.get(Vec2::new(0, 1)).unwrap(), 'c');
+        assert_eq!(array.to_string(), "ab\ncd\n");
+        let bytes: Array2D<u8> = "ab\ncd\n\n".parse().unwrap();
+        assert_eq!(bytes.get(Vec2::new(1, 0)).unwrap(), b'b');
+    }
+
+    #[test]
+    fn parse_reports_ragged_lines_and_bad_values() {
+        let err = "abc\nabc\nab\n".parse::<Array2D<char>>().unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 3,
+                column: 3,
+                ..
+            }
+        ));
+        assert!(matches!(
+            "".parse::<Array2D<u8>>(),
+            Err(AocError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            "\nab\ncd".parse::<Array2D<char>>(),
+            Err(AocError::Parse {
+                line: 1,
+                column: 1,
+                ..
+            })
+        ));
+
+        let digits = |ch: char| {
+            ch.to_digit(10)
+                .ok_or_else(|| "Expected a digit".to_string())
+        };
+        assert_eq!(
+            Array2D::parse_with("12\n34", digits)
+                .unwrap()
+                .get(Vec2::new(1, 1))
+                .unwrap(),
+            4
+        );
+        let err = Array2D::parse_with("12\n3x", digits).unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 2,
+                column: 2,
+                ..
+            }
+        ));
+    }
+}
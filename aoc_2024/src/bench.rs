@@ -0,0 +1,555 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::cli::InputSource;
+use crate::diagnostics;
+use crate::error::{parse_field, AocError};
+use crate::inputs::YEAR;
+use crate::solution::{Day, Part};
+
+/// Environment variable that overrides where the benchmark history lives.
+pub const BENCH_DIR_VAR: &str = "AOC_BENCH_DIR";
+
+const DEFAULT_BENCH_DIR: &str = "benchmarks";
+
+/// A timed step of solving a day.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Step {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+impl Step {
+    fn from_str(value: &str) -> Option<Step> {
+        match value {
+            "parse" => Some(Step::Parse),
+            "part 1" => Some(Step::Part(Part::One)),
+            "part 2" => Some(Step::Part(Part::Two)),
+            _ => None,
+        }
+    }
+}
+
+/// Spread of the wall times measured over a number of runs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Panics if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        Stats {
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+/// Timings of one day, for parsing and for each part that was solved.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchResult {
+    pub day: u32,
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+impl BenchResult {
+    /// The parse step followed by the parts.
+    pub fn steps(&self) -> impl Iterator<Item = (Step, &Stats)> {
+        std::iter::once((Step::Parse, &self.parse)).chain(
+            self.parts
+                .iter()
+                .map(|(part, stats)| (Step::Part(*part), stats)),
+        )
+    }
+}
+
+/// Solve the parts of a day `iterations` times and collect the timings. Diagnostics are not
+/// built while timing, and parts that are not solved yet are left out.
+pub fn bench(
+    day: &Day,
+    raw_input: &str,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<BenchResult, AocError> {
+    if iterations == 0 {
+        return Err(AocError::InvalidInput(
+            "Need at least one iteration".to_string(),
+        ));
+    }
+
+    let runs: Vec<_> = (0..iterations)
+        .map(|_| diagnostics::discard(|| (day.solve)(raw_input, parts)))
+        .collect::<Result<_, AocError>>()?;
+
+    let parse: Vec<Duration> = runs.iter().map(|solved| solved.parse_duration).collect();
+    let parts = parts
+        .iter()
+        .enumerate()
+        .filter(|&(idx, _)| runs[0].answers[idx].answer.is_some())
+        .map(|(idx, &part)| {
+            let samples: Vec<Duration> = runs
+                .iter()
+                .map(|solved| solved.answers[idx].duration)
+                .collect();
+            (part, Stats::from_samples(&samples))
+        })
+        .collect();
+
+    Ok(BenchResult {
+        day: day.number,
+        parse: Stats::from_samples(&parse),
+        parts,
+    })
+}
+
+/// A duration in the largest unit that keeps it at or above 1.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{} ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} s", duration.as_secs_f64())
+    }
+}
+
+/// A table with a row per step of each day and a total over all of them.
+pub fn format_table(results: &[BenchResult]) -> String {
+    let mut rows: Vec<[String; 5]> = Vec::new();
+    let mut row = |day: String, step: String, stats: &Stats| {
+        rows.push([
+            day,
+            step,
+            format_duration(stats.min),
+            format_duration(stats.median),
+            format_duration(stats.max),
+        ])
+    };
+
+    let mut total = Stats {
+        min: Duration::ZERO,
+        median: Duration::ZERO,
+        max: Duration::ZERO,
+    };
+    for result in results {
+        for (step, stats) in result.steps() {
+            row(result.day.to_string(), step.to_string(), stats);
+            total.min += stats.min;
+            total.median += stats.median;
+            total.max += stats.max;
+        }
+    }
+    row("Total".to_string(), String::new(), &total);
+
+    let header = ["Day", "Step", "Min", "Median", "Max"].map(str::to_string);
+    let mut widths = header.clone().map(|cell| cell.chars().count());
+    for cells in &rows {
+        for (width, cell) in widths.iter_mut().zip(cells) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    for cells in std::iter::once(&header).chain(&rows) {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(idx, (cell, width))| {
+                // Text columns are aligned left, durations right
+                let padding = " ".repeat(width - cell.chars().count());
+                if idx < 2 {
+                    format!("{}{}", cell, padding)
+                } else {
+                    format!("{}{}", padding, cell)
+                }
+            })
+            .collect();
+        table.push_str(line.join("  ").trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+/// The commit that is checked out in the repository holding the crate, with `-dirty` appended
+/// if there are uncommitted changes, or `unknown` if the crate is not in a git repository.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        std::process::Command::new("git")
+            .arg("-C")
+            .arg(crate::crate_path(""))
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(commit) => match git(&["status", "--porcelain"]) {
+            Some(changes) if changes.is_empty() => commit,
+            _ => format!("{}-dirty", commit),
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+/// What the history calls the input that was benchmarked: `example N` for the examples, and
+/// `input` for the real puzzle input, wherever it was read from. Only results for the same input
+/// are compared.
+pub fn input_label(source: &InputSource) -> String {
+    match source {
+        InputSource::Example(number) => format!("example {}", number),
+        _ => "input".to_string(),
+    }
+}
+
+/// Benchmark results of one step on an input, measured at a commit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    pub commit: String,
+    /// See `input_label`.
+    pub input: String,
+    pub day: u32,
+    pub step: Step,
+    pub stats: Stats,
+}
+
+const HISTORY_HEADER: &str = "commit,input,day,step,min_ns,median_ns,max_ns";
+
+/// Benchmark results over time, stored as `<dir>/<year>.csv` with a line per commit, input, day
+/// and step. Lines are kept in the order they were recorded, so the last ones are the most recent.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct History {
+    pub entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// `<year>.csv` under `$AOC_BENCH_DIR`, which defaults to `benchmarks`.
+    pub fn path_from_env() -> PathBuf {
+        crate::env_or_crate_path(BENCH_DIR_VAR, DEFAULT_BENCH_DIR).join(format!("{}.csv", YEAR))
+    }
+
+    pub fn parse(text: &str) -> Result<History, AocError> {
+        let mut history = History::default();
+
+        for (line_index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || (line_index == 0 && line == HISTORY_HEADER) {
+                continue;
+            }
+            let fields: Vec<&str> = line.split(',').collect();
+            let [commit, input, day, step, min, median, max] = fields[..] else {
+                return Err(AocError::parse(line_index, 1, "Expected 7 fields"));
+            };
+            let step = Step::from_str(step).ok_or_else(|| {
+                let column = step.as_ptr() as usize - line.as_ptr() as usize + 1;
+                AocError::parse(line_index, column, format!("Unknown step '{}'", step))
+            })?;
+            let nanos = |field| parse_field(line_index, line, field).map(Duration::from_nanos);
+
+            history.entries.push(HistoryEntry {
+                commit: commit.to_string(),
+                input: input.to_string(),
+                day: parse_field(line_index, line, day)?,
+                step,
+                stats: Stats {
+                    min: nanos(min)?,
+                    median: nanos(median)?,
+                    max: nanos(max)?,
+                },
+            });
+        }
+
+        Ok(history)
+    }
+
+    /// No entries if nothing has been recorded yet.
+    pub fn load(path: &Path) -> Result<History, AocError> {
+        crate::load_or_default(path, History::parse)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        crate::save(path, &self.to_string())
+    }
+
+    /// Add the results measured on an input at a commit, replacing what was recorded for the
+    /// same commit and input.
+    pub fn record(&mut self, commit: &str, input: &str, results: &[BenchResult]) {
+        for result in results {
+            for (step, stats) in result.steps() {
+                self.entries.retain(|entry| {
+                    (
+                        entry.commit.as_str(),
+                        entry.input.as_str(),
+                        entry.day,
+                        entry.step,
+                    ) != (commit, input, result.day, step)
+                });
+                self.entries.push(HistoryEntry {
+                    commit: commit.to_string(),
+                    input: input.to_string(),
+                    day: result.day,
+                    step,
+                    stats: *stats,
+                });
+            }
+        }
+    }
+
+    /// The most recent entry for a step that was measured on the same input at another commit.
+    pub fn baseline(
+        &self,
+        commit: &str,
+        input: &str,
+        day: u32,
+        step: Step,
+    ) -> Option<&HistoryEntry> {
+        self.entries.iter().rev().find(|entry| {
+            entry.commit != commit && entry.input == input && entry.day == day && entry.step == step
+        })
+    }
+}
+
+impl Display for History {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", HISTORY_HEADER)?;
+        for entry in &self.entries {
+            writeln!(
+                f,
+                "{},{},{},{},{},{},{}",
+                entry.commit,
+                entry.input,
+                entry.day,
+                entry.step,
+                entry.stats.min.as_nanos(),
+                entry.stats.median.as_nanos(),
+                entry.stats.max.as_nanos()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The median of a step compared with its baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub day: u32,
+    pub step: Step,
+    pub baseline_commit: String,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// The current median is slower than the baseline by more than the threshold.
+    pub regressed: bool,
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let change = 100.0 * (self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1.0);
+        write!(
+            f,
+            "Day {} {}: {} -> {} ({:+.0}% vs {}){}",
+            self.day,
+            self.step,
+            format_duration(self.baseline),
+            format_duration(self.current),
+            change,
+            self.baseline_commit,
+            if self.regressed { " REGRESSED" } else { "" }
+        )
+    }
+}
+
+/// Compare the medians of the results on an input with their baselines in the history. A step
+/// regressed if its median grew by more than `threshold_percent`. Steps without a baseline are
+/// left out.
+pub fn compare(
+    history: &History,
+    commit: &str,
+    input: &str,
+    results: &[BenchResult],
+    threshold_percent: f64,
+) -> Vec<Comparison> {
+    let mut comparisons = Vec::new();
+    for result in results {
+        for (step, stats) in result.steps() {
+            let Some(baseline) = history.baseline(commit, input, result.day, step) else {
+                continue;
+            };
+            let limit = baseline.stats.median.as_secs_f64() * (1.0 + threshold_percent / 100.0);
+            comparisons.push(Comparison {
+                day: result.day,
+                step,
+                baseline_commit: baseline.commit.clone(),
+                baseline: baseline.stats.median,
+                current: stats.median,
+                regressed: stats.median.as_secs_f64() > limit,
+            });
+        }
+    }
+    comparisons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_from_samples() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(
+            Stats::from_samples(&[ms(5), ms(1), ms(3)]),
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(5)
+            }
+        );
+        assert_eq!(
+            Stats::from_samples(&[ms(4), ms(1), ms(2), ms(8)]).median,
+            ms(3)
+        );
+    }
+
+    #[test]
+    fn format_duration_picks_unit() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999 ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_340)), "12.3 µs");
+        assert_eq!(format_duration(Duration::from_micros(2_500)), "2.5 ms");
+        assert_eq!(format_duration(Duration::from_millis(1_500)), "1.50 s");
+    }
+
+    #[test]
+    fn bench_times_every_part() {
+        let day = crate::days::find_day(1).unwrap();
+        let result = bench(day, day.examples[0], &Part::BOTH, 3).unwrap();
+
+        assert_eq!(result.day, 1);
+        assert_eq!(
+            result
+                .parts
+                .iter()
+                .map(|(part, _)| *part)
+                .collect::<Vec<_>>(),
+            Part::BOTH
+        );
+        let table = format_table(&[result]);
+        assert!(table.starts_with("Day    Step"));
+        assert!(table.contains("\n1      part 2"));
+        assert!(table.lines().last().unwrap().starts_with("Total"));
+    }
+
+    #[test]
+    fn bench_leaves_out_unsolved_parts() {
+        let day = crate::days::find_day(5).unwrap();
+        let result = bench(day, day.examples[0], &Part::BOTH, 1).unwrap();
+
+        assert_eq!(result.parts.len(), 1);
+        assert_eq!(result.parts[0].0, Part::One);
+        assert!(!format_table(&[result]).contains("part 2"));
+    }
+
+    fn result(day: u32, parse_median_ms: u64) -> BenchResult {
+        let stats = |median| Stats {
+            min: Duration::from_millis(1),
+            median: Duration::from_millis(median),
+            max: Duration::from_millis(100),
+        };
+        BenchResult {
+            day,
+            parse: stats(parse_median_ms),
+            parts: vec![(Part::One, stats(10))],
+        }
+    }
+
+    #[test]
+    fn history_round_trips_through_csv() {
+        let mut history = History::default();
+        history.record("abc123", "input", &[result(1, 5), result(2, 5)]);
+        history.record("def456", "input", &[result(1, 6)]);
+        history.record("abc123", "example 1", &[result(1, 1)]);
+        // Recording a commit and input again replaces its entries
+        history.record("abc123", "input", &[result(2, 7)]);
+
+        let text = history.to_string();
+        assert!(text.starts_with("commit,input,day,step,min_ns,median_ns,max_ns\n"));
+        assert!(text.ends_with("abc123,input,2,part 1,1000000,10000000,100000000\n"));
+        assert_eq!(History::parse(&text).unwrap(), history);
+        assert_eq!(history.entries.len(), 8);
+    }
+
+    #[test]
+    fn history_reports_line_of_bad_entry() {
+        let err =
+            History::parse("abc,input,1,parse,1,2,3\nabc,input,1,part 3,1,2,3\n").unwrap_err();
+
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 2,
+                column: 13,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn compare_flags_medians_beyond_threshold() {
+        let mut history = History::default();
+        history.record("old", "input", &[result(1, 10)]);
+        history.record("new", "input", &[result(1, 100)]);
+
+        let comparisons = compare(&history, "new", "input", &[result(1, 12)], 25.0);
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].baseline_commit, "old");
+        assert!(!comparisons[0].regressed);
+
+        let comparisons = compare(&history, "new", "input", &[result(1, 13)], 25.0);
+        assert!(comparisons[0].regressed);
+        assert!(comparisons[0]
+            .to_string()
+            .ends_with("(+30% vs old) REGRESSED"));
+        assert!(!comparisons[1].regressed);
+    }
+
+    #[test]
+    fn compare_only_uses_baselines_from_the_same_input() {
+        let mut history = History::default();
+        history.record("old", "example 1", &[result(1, 1)]);
+
+        assert!(compare(&history, "new", "input", &[result(1, 10)], 25.0).is_empty());
+        assert_eq!(
+            compare(&history, "new", "example 1", &[result(1, 1)], 25.0).len(),
+            2
+        );
+        assert_eq!(input_label(&InputSource::Example(2)), "example 2");
+        assert_eq!(input_label(&InputSource::Stdin), "input");
+    }
+}
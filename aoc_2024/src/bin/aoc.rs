@@ -0,0 +1,500 @@
+use std::path::PathBuf;
+
+use aoc_2024::answers::{self, Ledger, Verdict};
+use aoc_2024::bench::{
+    bench, compare, current_commit, format_table, input_label, BenchResult, History,
+};
+use aoc_2024::cli::{self, CliError, InputSource, Options};
+use aoc_2024::config::Config;
+use aoc_2024::days::{find_day, DAYS};
+use aoc_2024::diagnostics;
+use aoc_2024::error::AocError;
+use aoc_2024::inputs::InputCache;
+use aoc_2024::remote::{fetch_to_cache, submit_to_ledger, FetchOutcome, Remote, SubmitOutcome};
+use aoc_2024::solution::{Day, Part};
+
+const USAGE: &str = "Usage:
+    aoc run --day <N> [options] [<input>]
+    aoc run --all [options] [<input cache directory>]
+    aoc bench --day <N> [bench options] [options] [<input>]
+    aoc bench --all [bench options] [options] [<input cache directory>]
+    aoc input import <file> --day <N>
+    aoc input fetch --day <N>
+    aoc verify
+    aoc submit --day <N> --part <1|2> [<answer>]
+
+Paths that are not set through the environment are relative to the aoc_2024 crate directory.
+Inputs are cached as <dir>/2024/day_<NN>.txt, where <dir> is $AOC_INPUT_DIR or 'inputs'.
+Known answers are kept in <dir>/2024.toml, where <dir> is $AOC_ANSWERS_DIR or 'answers'.
+Fetching and submitting read the session token from aoc.toml, or the file in $AOC_CONFIG.
+Submitting without an answer solves the part from the cached input.
+
+Bench options:
+    --iterations <K>        Solve each day <K> times [default: 10]
+    --save                  Record the results for the current commit and input in the history
+    --compare               Compare the medians with the most recent other commit in the history
+                            that was benchmarked on the same input (the same example, or the
+                            real input)
+    --threshold <percent>   How much slower a median may get before it counts as a regression
+                            [default: 25]
+The benchmark history is kept in <dir>/2024.csv, where <dir> is $AOC_BENCH_DIR or 'benchmarks'.";
+
+struct BenchArgs {
+    iterations: usize,
+    save: bool,
+    compare: bool,
+    threshold_percent: f64,
+}
+
+impl Default for BenchArgs {
+    fn default() -> BenchArgs {
+        BenchArgs {
+            iterations: 10,
+            save: false,
+            compare: false,
+            threshold_percent: 25.0,
+        }
+    }
+}
+
+struct RunArgs {
+    day_number: Option<u32>,
+    all: bool,
+    bench: BenchArgs,
+    options: Options,
+}
+
+fn parse_day_number(args: &mut impl Iterator<Item = String>) -> Result<u32, CliError> {
+    let value = args
+        .next()
+        .ok_or_else(|| CliError::Usage("Expected a value after --day".to_string()))?;
+    value
+        .parse()
+        .map_err(|_| CliError::Usage(format!("Day must be a number: {}", value)))
+}
+
+fn find_day_or_exit(day_number: u32) -> &'static Day {
+    match find_day(day_number) {
+        Some(day) => day,
+        None => cli::exit_with(
+            CliError::Usage(format!("No solution registered for day {}", day_number)),
+            USAGE,
+        ),
+    }
+}
+
+/// Arguments of `run` and `bench`. Only `bench` takes the bench options.
+fn parse_run_args(
+    command: &str,
+    args: impl IntoIterator<Item = String>,
+) -> Result<RunArgs, CliError> {
+    let mut run_args = RunArgs {
+        day_number: None,
+        all: false,
+        bench: BenchArgs::default(),
+        options: Options::default(),
+    };
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => run_args.day_number = Some(parse_day_number(&mut args)?),
+            "--all" => run_args.all = true,
+            "--iterations" if command == "bench" => {
+                let value = args.next().ok_or_else(|| {
+                    CliError::Usage("Expected a value after --iterations".to_string())
+                })?;
+                run_args.bench.iterations = match value.parse() {
+                    Ok(iterations) if iterations > 0 => iterations,
+                    _ => {
+                        return Err(CliError::Usage(format!(
+                            "Iterations must be a positive number: {}",
+                            value
+                        )))
+                    }
+                };
+            }
+            "--save" if command == "bench" => run_args.bench.save = true,
+            "--compare" if command == "bench" => run_args.bench.compare = true,
+            "--threshold" if command == "bench" => {
+                let value = args.next().ok_or_else(|| {
+                    CliError::Usage("Expected a value after --threshold".to_string())
+                })?;
+                run_args.bench.threshold_percent = match value.parse() {
+                    Ok(percent) if percent >= 0.0 => percent,
+                    _ => {
+                        return Err(CliError::Usage(format!(
+                            "Threshold must be a non-negative percentage: {}",
+                            value
+                        )))
+                    }
+                };
+            }
+            _ => {
+                if !run_args.options.parse_arg(&arg, &mut args)? {
+                    return Err(CliError::Usage(format!("Unknown option: {}", arg)));
+                }
+            }
+        }
+    }
+
+    if run_args.all == run_args.day_number.is_some() {
+        return Err(CliError::Usage(
+            "Expected exactly one of --day and --all".to_string(),
+        ));
+    }
+    Ok(run_args)
+}
+
+fn selected_days(run_args: &RunArgs) -> Vec<&'static Day> {
+    match run_args.day_number {
+        Some(day_number) => vec![find_day_or_exit(day_number)],
+        None => DAYS.iter().collect(),
+    }
+}
+
+fn selected_source(run_args: &RunArgs) -> InputSource {
+    match (run_args.options.source(), run_args.all) {
+        // A path given with --all is the root of the input cache
+        (InputSource::File(directory), true) => InputSource::Cache(InputCache::new(directory)),
+        (InputSource::Stdin, true) => cli::exit_with(
+            CliError::Usage("Cannot read all days from stdin".to_string()),
+            USAGE,
+        ),
+        (source, _) => source,
+    }
+}
+
+fn run_command(args: impl IntoIterator<Item = String>) {
+    let run_args = match parse_run_args("run", args) {
+        Ok(run_args) => run_args,
+        Err(err) => cli::exit_with(err, USAGE),
+    };
+    let options = &run_args.options;
+    let source = selected_source(&run_args);
+
+    let mut failed = false;
+    for day in selected_days(&run_args) {
+        if options.format == cli::Format::Text && !options.quiet {
+            println!("Day {}", day.number);
+        }
+        if let Err(err) = cli::run(day, &source, options) {
+            eprintln!("{}: {}", source, err);
+            failed = true;
+        }
+    }
+
+    if failed {
+        std::process::exit(cli::EXIT_FAILURE);
+    }
+}
+
+/// Compare with and/or save to the benchmark history. Returns whether any step regressed.
+fn update_history(
+    bench_args: &BenchArgs,
+    source: &InputSource,
+    results: &[BenchResult],
+) -> Result<bool, AocError> {
+    let path = History::path_from_env();
+    let mut history = History::load(&path)?;
+    let commit = current_commit();
+    let input = input_label(source);
+
+    let mut regressed = false;
+    if bench_args.compare {
+        let comparisons = compare(
+            &history,
+            &commit,
+            &input,
+            results,
+            bench_args.threshold_percent,
+        );
+        if comparisons.is_empty() {
+            println!(
+                "No baseline from another commit on {} to compare with",
+                input
+            );
+        }
+        for comparison in comparisons {
+            println!("{}", comparison);
+            regressed |= comparison.regressed;
+        }
+    }
+    if bench_args.save {
+        history.record(&commit, &input, results);
+        history.save(&path)?;
+        println!(
+            "Recorded results for {} on {} in {}",
+            commit,
+            input,
+            path.display()
+        );
+    }
+    Ok(regressed)
+}
+
+fn bench_command(args: impl IntoIterator<Item = String>) {
+    let run_args = match parse_run_args("bench", args) {
+        Ok(run_args) => run_args,
+        Err(err) => cli::exit_with(err, USAGE),
+    };
+    let source = selected_source(&run_args);
+
+    let mut results = Vec::new();
+    let mut failed = false;
+    for day in selected_days(&run_args) {
+        let result = source.read(day).and_then(|raw_input| {
+            bench(
+                day,
+                &raw_input,
+                &run_args.options.parts,
+                run_args.bench.iterations,
+            )
+        });
+        match result {
+            Ok(result) => results.push(result),
+            Err(err) => {
+                eprintln!("Day {}: {}: {}", day.number, source, err);
+                failed = true;
+            }
+        }
+    }
+
+    println!(
+        "{} iteration(s) per day\n\n{}",
+        run_args.bench.iterations,
+        format_table(&results)
+    );
+    if run_args.bench.compare || run_args.bench.save {
+        match update_history(&run_args.bench, &source, &results) {
+            Ok(regressed) => failed |= regressed,
+            Err(err) => {
+                eprintln!("{}: {}", History::path_from_env().display(), err);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        std::process::exit(cli::EXIT_FAILURE);
+    }
+}
+
+fn input_command(mut args: impl Iterator<Item = String>) {
+    let command = match args.next() {
+        Some(command) if command == "import" || command == "fetch" => command,
+        Some(other) => cli::exit_with(
+            CliError::Usage(format!("Unknown input command: {}", other)),
+            USAGE,
+        ),
+        None => cli::exit_with(
+            CliError::Usage("Expected an input command".to_string()),
+            USAGE,
+        ),
+    };
+
+    let mut day_number: Option<u32> = None;
+    let mut file: Option<PathBuf> = None;
+    while let Some(arg) = args.next() {
+        let parsed = match arg.as_str() {
+            "-h" | "--help" => Err(CliError::Help),
+            "--day" => parse_day_number(&mut args).map(|number| day_number = Some(number)),
+            _ if command == "import" && !arg.starts_with('-') && file.is_none() => {
+                file = Some(PathBuf::from(arg));
+                Ok(())
+            }
+            _ => Err(CliError::Usage(format!("Unexpected argument: {}", arg))),
+        };
+        if let Err(err) = parsed {
+            cli::exit_with(err, USAGE);
+        }
+    }
+
+    let Some(day_number) = day_number else {
+        cli::exit_with(CliError::Usage("Expected --day".to_string()), USAGE);
+    };
+    let cache = InputCache::from_env();
+
+    if command == "fetch" {
+        let result = Config::load(&Config::path_from_env())
+            .map(|config| Remote::from_config(config, &cache))
+            .and_then(|remote| fetch_to_cache(&remote, &cache, day_number));
+        match result {
+            Ok(FetchOutcome::AlreadyCached(path)) => println!(
+                "Input for day {} is already cached at {}",
+                day_number,
+                path.display()
+            ),
+            Ok(FetchOutcome::Downloaded(path)) => {
+                println!("Stored input for day {} at {}", day_number, path.display())
+            }
+            Err(err) => {
+                eprintln!("Failed to fetch input for day {}: {}", day_number, err);
+                std::process::exit(cli::EXIT_FAILURE);
+            }
+        }
+        return;
+    }
+
+    let Some(file) = file else {
+        cli::exit_with(CliError::Usage("Expected a file".to_string()), USAGE);
+    };
+    let day = find_day_or_exit(day_number);
+
+    let result = std::fs::read_to_string(&file)
+        .map_err(Into::into)
+        .and_then(|raw_input| cache.import(day, &raw_input));
+    match result {
+        Ok(path) => println!("Stored input for day {} at {}", day.number, path.display()),
+        Err(err) => {
+            eprintln!("{}: {}", file.display(), err);
+            std::process::exit(cli::EXIT_FAILURE);
+        }
+    }
+}
+
+fn verify_command(mut args: impl Iterator<Item = String>) {
+    if let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => cli::exit_with(CliError::Help, USAGE),
+            _ => cli::exit_with(
+                CliError::Usage(format!("Unexpected argument: {}", arg)),
+                USAGE,
+            ),
+        }
+    }
+
+    let ledger_path = Ledger::path_from_env();
+    let ledger = match Ledger::load(&ledger_path) {
+        Ok(ledger) => ledger,
+        Err(err) => {
+            eprintln!("{}: {}", ledger_path.display(), err);
+            std::process::exit(cli::EXIT_FAILURE);
+        }
+    };
+
+    let cache = InputCache::from_env();
+    let mut failed = false;
+    for day in DAYS.iter() {
+        if !cache.contains(day.number) {
+            println!("Day {}: skipped, no cached input", day.number);
+            continue;
+        }
+        let result = cache
+            .read(day.number)
+            .and_then(|raw_input| answers::verify(&ledger, day, &raw_input));
+        match result {
+            Ok(checks) => {
+                for (part, check) in checks {
+                    println!("Day {} part {}: {}", day.number, part, check);
+                    failed |= check.is_failure();
+                }
+            }
+            Err(err) => {
+                println!("Day {}: {}", day.number, err);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        std::process::exit(cli::EXIT_FAILURE);
+    }
+}
+
+fn submit(day: &Day, part: Part, answer: Option<String>) -> Result<SubmitOutcome, AocError> {
+    let cache = InputCache::from_env();
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let raw_input = cache.read(day.number)?;
+            diagnostics::discard(|| (day.solve)(&raw_input, &[part]))?
+        }
+        .answers
+        .remove(0)
+        .answer
+        .ok_or_else(|| AocError::InvalidInput("The part is not solved yet".to_string()))?,
+    };
+    println!("Answer for day {} part {}: {}", day.number, part, answer);
+
+    let ledger_path = Ledger::path_from_env();
+    let mut ledger = Ledger::load(&ledger_path)?;
+    let remote = Remote::from_config(Config::load(&Config::path_from_env())?, &cache);
+    let outcome = submit_to_ledger(&remote, &mut ledger, day.number, part, &answer)?;
+    if let SubmitOutcome::Submitted(_) = outcome {
+        ledger.save(&ledger_path)?;
+    }
+    Ok(outcome)
+}
+
+fn submit_command(mut args: impl Iterator<Item = String>) {
+    let mut day_number: Option<u32> = None;
+    let mut part: Option<Part> = None;
+    let mut answer: Option<String> = None;
+    while let Some(arg) = args.next() {
+        let parsed = match arg.as_str() {
+            "-h" | "--help" => Err(CliError::Help),
+            "--day" => parse_day_number(&mut args).map(|number| day_number = Some(number)),
+            "--part" => {
+                part = match args.next().as_deref() {
+                    Some("1") => Some(Part::One),
+                    Some("2") => Some(Part::Two),
+                    _ => cli::exit_with(CliError::Usage("Part must be 1 or 2".to_string()), USAGE),
+                };
+                Ok(())
+            }
+            _ if !arg.starts_with('-') && answer.is_none() => {
+                answer = Some(arg);
+                Ok(())
+            }
+            _ => Err(CliError::Usage(format!("Unexpected argument: {}", arg))),
+        };
+        if let Err(err) = parsed {
+            cli::exit_with(err, USAGE);
+        }
+    }
+
+    let (Some(day_number), Some(part)) = (day_number, part) else {
+        cli::exit_with(
+            CliError::Usage("Expected --day and --part".to_string()),
+            USAGE,
+        );
+    };
+    let day = find_day_or_exit(day_number);
+
+    match submit(day, part, answer) {
+        Ok(SubmitOutcome::Known(Verdict::Correct)) => {
+            println!("Already known to be correct, not submitting")
+        }
+        Ok(SubmitOutcome::Known(verdict)) => {
+            println!("Already known to be {}, not submitting", verdict);
+            std::process::exit(cli::EXIT_FAILURE);
+        }
+        Ok(SubmitOutcome::Submitted(verdict)) => {
+            println!("The answer is {}", verdict);
+            if verdict != Verdict::Correct {
+                std::process::exit(cli::EXIT_FAILURE);
+            }
+        }
+        Err(err) => {
+            eprintln!("Failed to submit: {}", err);
+            std::process::exit(cli::EXIT_FAILURE);
+        }
+    }
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    match args.next().as_deref() {
+        Some("run") => run_command(args),
+        Some("bench") => bench_command(args),
+        Some("input") => input_command(args),
+        Some("verify") => verify_command(args),
+        Some("submit") => submit_command(args),
+        Some("-h") | Some("--help") => cli::exit_with(CliError::Help, USAGE),
+        Some(other) => cli::exit_with(
+            CliError::Usage(format!("Unknown command: {}", other)),
+            USAGE,
+        ),
+        None => cli::exit_with(CliError::Usage("Expected a command".to_string()), USAGE),
+    }
+}
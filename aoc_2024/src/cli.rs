@@ -0,0 +1,432 @@
+use std::fmt::Display;
+use std::io::Read;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::bench::format_duration;
+use crate::error::AocError;
+use crate::inputs::InputCache;
+use crate::solution::{Day, Part, Solved};
+
+pub const OPTIONS_HELP: &str = "Options:
+    --input <path>          Read the input from <path>, or from stdin if <path> is '-'.
+                            Without an input, it is read from the input cache in
+                            $AOC_INPUT_DIR, or 'inputs' in the crate directory if that
+                            is not set.
+    --example <N>           Use the N-th example input of the day instead (1-based)
+    --part <1|2|both>       Which half to solve [default: both]
+    --format <text|json>    Print the answers as text, or as a line of JSON per part with
+                            its answer, duration and diagnostics [default: text]
+    --quiet                 Do not print diagnostics or timings, only the answers
+    --trace                 Trace each step as a diagnostic, for the days that support it
+    -h, --help              Print this help";
+
+/// Exit code for a successful run.
+pub const EXIT_OK: i32 = 0;
+/// Exit code when solving fails, e.g. because the input is malformed.
+pub const EXIT_FAILURE: i32 = 1;
+/// Exit code when the command line is invalid.
+pub const EXIT_USAGE: i32 = 2;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Example(usize),
+    Cache(InputCache),
+}
+
+impl InputSource {
+    pub fn read(&self, day: &Day) -> Result<String, AocError> {
+        match self {
+            InputSource::File(path) => Ok(std::fs::read_to_string(path)?),
+            InputSource::Stdin => {
+                let mut raw_input = String::new();
+                std::io::stdin().read_to_string(&mut raw_input)?;
+                Ok(raw_input)
+            }
+            InputSource::Example(number) => number
+                .checked_sub(1)
+                .and_then(|idx| day.examples.get(idx))
+                .map(|example| example.to_string())
+                .ok_or_else(|| {
+                    AocError::InvalidInput(format!(
+                        "Day {} has {} example(s), there is no example {}",
+                        day.number,
+                        day.examples.len(),
+                        number
+                    ))
+                }),
+            InputSource::Cache(cache) => cache.read(day.number),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::Example(number) => write!(f, "<example {}>", number),
+            InputSource::Cache(cache) => write!(f, "<input cache in {}>", cache.root().display()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CliError {
+    /// Help was requested. Not an error as such, but it stops argument parsing.
+    Help,
+    Usage(String),
+}
+
+/// The options shared by all binaries.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Options {
+    pub input: Option<InputSource>,
+    pub parts: Vec<Part>,
+    pub format: Format,
+    pub quiet: bool,
+    pub trace: bool,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            input: None,
+            parts: Part::BOTH.to_vec(),
+            format: Format::Text,
+            quiet: false,
+            trace: false,
+        }
+    }
+}
+
+fn value_of(flag: &str, rest: &mut impl Iterator<Item = String>) -> Result<String, CliError> {
+    rest.next()
+        .ok_or_else(|| CliError::Usage(format!("Expected a value after {}", flag)))
+}
+
+impl Options {
+    /// Consume `arg`, and its value from `rest` if it takes one. Returns false if `arg` is not
+    /// one of the shared options, so the caller can handle its own.
+    pub fn parse_arg(
+        &mut self,
+        arg: &str,
+        rest: &mut impl Iterator<Item = String>,
+    ) -> Result<bool, CliError> {
+        match arg {
+            "-h" | "--help" => return Err(CliError::Help),
+            "--input" => {
+                let value = value_of(arg, rest)?;
+                self.set_input(&value)?;
+            }
+            "--example" => {
+                let value = value_of(arg, rest)?;
+                let number: usize =
+                    value
+                        .parse()
+                        .ok()
+                        .filter(|&number| number > 0)
+                        .ok_or_else(|| {
+                            CliError::Usage(format!("Example must be a positive number: {}", value))
+                        })?;
+                self.set_source(InputSource::Example(number))?;
+            }
+            "--part" => {
+                self.parts = match value_of(arg, rest)?.as_str() {
+                    "1" => vec![Part::One],
+                    "2" => vec![Part::Two],
+                    "both" => Part::BOTH.to_vec(),
+                    other => {
+                        return Err(CliError::Usage(format!(
+                            "Part must be 1, 2 or both: {}",
+                            other
+                        )))
+                    }
+                };
+            }
+            "--format" => {
+                self.format = match value_of(arg, rest)?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    other => {
+                        return Err(CliError::Usage(format!(
+                            "Format must be text or json: {}",
+                            other
+                        )))
+                    }
+                };
+            }
+            "--quiet" => self.quiet = true,
+            "--trace" => self.trace = true,
+            _ if arg == "-" || !arg.starts_with('-') => {
+                // A bare path works like --input, for backwards compatibility
+                self.set_input(arg)?;
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn set_input(&mut self, value: &str) -> Result<(), CliError> {
+        if value == "-" {
+            self.set_source(InputSource::Stdin)
+        } else {
+            self.set_source(InputSource::File(PathBuf::from(value)))
+        }
+    }
+
+    fn set_source(&mut self, source: InputSource) -> Result<(), CliError> {
+        if self.input.is_some() {
+            return Err(CliError::Usage("Only one input may be given".to_string()));
+        }
+        self.input = Some(source);
+        Ok(())
+    }
+
+    /// Where to read the input from, falling back to the input cache.
+    pub fn source(&self) -> InputSource {
+        self.input
+            .clone()
+            .unwrap_or_else(|| InputSource::Cache(InputCache::from_env()))
+    }
+
+    /// Parse a command line that only contains the shared options.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Options, CliError> {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if !options.parse_arg(&arg, &mut args)? {
+                return Err(CliError::Usage(format!("Unknown option: {}", arg)));
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Print help or a usage error and exit with the matching exit code.
+pub fn exit_with(err: CliError, usage: &str) -> ! {
+    match err {
+        CliError::Help => {
+            println!("{}\n\n{}", usage, OPTIONS_HELP);
+            std::process::exit(EXIT_OK);
+        }
+        CliError::Usage(message) => {
+            eprintln!(
+                "error: {}\n\n{}\n\nRun with --help for more information.",
+                message, usage
+            );
+            std::process::exit(EXIT_USAGE);
+        }
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::from("\"");
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            ch if ch.is_control() => escaped.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// The outcome of solving one part of a day, as the runner reports it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunResult {
+    pub day: u32,
+    pub part: Part,
+    pub answer: Option<String>,
+    /// Wall time spent solving the part, not counting parsing.
+    pub duration: Duration,
+    /// Diagnostics from parsing the input, followed by those from solving the part.
+    pub diagnostics: Vec<String>,
+}
+
+impl RunResult {
+    pub fn from_solved(day: u32, solved: &Solved) -> Vec<RunResult> {
+        solved
+            .answers
+            .iter()
+            .map(|answer| RunResult {
+                day,
+                part: answer.part,
+                answer: answer.answer.clone(),
+                duration: answer.duration,
+                diagnostics: solved
+                    .parse_diagnostics
+                    .iter()
+                    .chain(&answer.diagnostics)
+                    .cloned()
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// The result as a single line of JSON, e.g.
+    /// `{"day":5,"part":1,"answer":"143","duration_ns":5120,"diagnostics":["..."]}`.
+    pub fn to_json(&self) -> String {
+        let diagnostics: Vec<String> = self.diagnostics.iter().map(|d| json_string(d)).collect();
+        format!(
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"duration_ns\":{},\"diagnostics\":[{}]}}",
+            self.day,
+            self.part,
+            self.answer
+                .as_deref()
+                .map(json_string)
+                .unwrap_or_else(|| "null".to_string()),
+            self.duration.as_nanos(),
+            diagnostics.join(",")
+        )
+    }
+}
+
+/// Print what solving a day gave in the requested format. Diagnostics are left out if quiet.
+pub fn print_solved(day: &Day, solved: &Solved, options: &Options) {
+    match options.format {
+        Format::Text => {
+            let diagnostics = |messages: &[String]| {
+                if !options.quiet {
+                    messages.iter().for_each(|message| println!("{}", message));
+                }
+            };
+            diagnostics(&solved.parse_diagnostics);
+            for answer in &solved.answers {
+                diagnostics(&answer.diagnostics);
+                println!("{}", answer);
+            }
+            if !options.quiet {
+                println!("{}", format_timings(solved));
+            }
+        }
+        Format::Json => {
+            for mut result in RunResult::from_solved(day.number, solved) {
+                if options.quiet {
+                    result.diagnostics.clear();
+                }
+                println!("{}", result.to_json());
+            }
+        }
+    }
+}
+
+/// One line with the wall time of each step, e.g. `Time: parse 12.0 µs, part 1 3.1 ms`.
+pub fn format_timings(solved: &Solved) -> String {
+    let mut steps = vec![format!("parse {}", format_duration(solved.parse_duration))];
+    for answer in &solved.answers {
+        steps.push(format!(
+            "part {} {}",
+            answer.part,
+            format_duration(answer.duration)
+        ));
+    }
+    format!("Time: {}", steps.join(", "))
+}
+
+/// Read the input and solve one day as the options say.
+pub fn run(day: &Day, source: &InputSource, options: &Options) -> Result<(), AocError> {
+    crate::diagnostics::set_tracing(options.trace);
+
+    let raw_input = source.read(day)?;
+    let solve = || (day.solve)(&raw_input, &options.parts);
+    let solved = if options.quiet {
+        crate::diagnostics::discard(solve)?
+    } else {
+        solve()?
+    };
+    print_solved(day, &solved, options);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, CliError> {
+        Options::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_selects_parts() {
+        assert_eq!(parse(&[]).unwrap().parts, Part::BOTH);
+        assert_eq!(parse(&["--part", "1"]).unwrap().parts, [Part::One]);
+        assert_eq!(parse(&["--part", "2"]).unwrap().parts, [Part::Two]);
+        assert_eq!(parse(&["--part", "both"]).unwrap().parts, Part::BOTH);
+        assert!(matches!(parse(&["--part", "3"]), Err(CliError::Usage(_))));
+        assert!(matches!(parse(&["--part"]), Err(CliError::Usage(_))));
+    }
+
+    #[test]
+    fn parse_reads_input_sources() {
+        let input = |args: &[&str]| parse(args).unwrap().input;
+
+        assert_eq!(input(&[]), None);
+        assert_eq!(input(&["-"]), Some(InputSource::Stdin));
+        assert_eq!(input(&["--input", "-"]), Some(InputSource::Stdin));
+        assert_eq!(
+            input(&["day_1.txt"]),
+            Some(InputSource::File(PathBuf::from("day_1.txt")))
+        );
+        assert_eq!(input(&["--example", "2"]), Some(InputSource::Example(2)));
+        assert!(matches!(
+            parse(&["--example", "0"]),
+            Err(CliError::Usage(_))
+        ));
+        assert!(matches!(
+            parse(&["--example", "x"]),
+            Err(CliError::Usage(_))
+        ));
+    }
+
+    #[test]
+    fn parse_rejects_a_second_input_and_unknown_flags() {
+        assert_eq!(
+            parse(&["a.txt", "--example", "1"]),
+            Err(CliError::Usage("Only one input may be given".to_string()))
+        );
+        assert!(matches!(parse(&["-", "b.txt"]), Err(CliError::Usage(_))));
+        assert_eq!(
+            parse(&["--verbose"]),
+            Err(CliError::Usage("Unknown option: --verbose".to_string()))
+        );
+        assert_eq!(parse(&["--quiet", "-h"]), Err(CliError::Help));
+    }
+
+    #[test]
+    fn parse_sets_flags() {
+        let options = parse(&["--quiet", "--trace", "--format", "json"]).unwrap();
+
+        assert!(options.quiet && options.trace);
+        assert_eq!(options.format, Format::Json);
+        assert!(matches!(
+            parse(&["--format", "xml"]),
+            Err(CliError::Usage(_))
+        ));
+    }
+
+    #[test]
+    fn run_results_carry_diagnostics_as_json() {
+        let day = crate::days::find_day(5).unwrap();
+        let solved = (day.solve)(day.examples[0], &[Part::One]).unwrap();
+        let results = RunResult::from_solved(day.number, &solved);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].answer.as_deref(), Some("143"));
+        assert_eq!(results[0].diagnostics, solved.parse_diagnostics);
+        let json = results[0].to_json();
+        assert!(json.starts_with("{\"day\":5,\"part\":1,\"answer\":\"143\",\"duration_ns\":"));
+        assert!(json.ends_with(",\"diagnostics\":[\"Found 21 rules and 6 page lists\"]}"));
+    }
+}
@@ -0,0 +1,117 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::error::AocError;
+use crate::toml::Document;
+
+/// Environment variable that overrides where the config file is read from.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+const DEFAULT_CONFIG_PATH: &str = "aoc.toml";
+
+/// Settings for talking to the Advent of Code website, read from `aoc.toml`:
+///
+/// ```toml
+/// session = "<value of the session cookie>"
+/// base_url = "https://adventofcode.com"
+/// request_interval_secs = 5
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub user_agent: String,
+    /// Minimum time between two requests to the website.
+    pub request_interval: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            session: None,
+            base_url: "https://adventofcode.com".to_string(),
+            user_agent: "github.com/eskaur/advent-of-code_rust".to_string(),
+            request_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+impl Config {
+    pub fn path_from_env() -> PathBuf {
+        crate::env_or_crate_path(CONFIG_VAR, DEFAULT_CONFIG_PATH)
+    }
+
+    pub fn parse(text: &str) -> Result<Config, AocError> {
+        let document = Document::parse(text)?;
+        let mut config = Config::default();
+
+        let string = |key: &str| -> Result<Option<String>, AocError> {
+            match document.get(None, key) {
+                Some(value) => value
+                    .as_str()
+                    .map(|value| Some(value.to_string()))
+                    .ok_or_else(|| AocError::Config(format!("Expected '{}' to be a string", key))),
+                None => Ok(None),
+            }
+        };
+
+        config.session = string("session")?;
+        if let Some(base_url) = string("base_url")? {
+            config.base_url = base_url.trim_end_matches('/').to_string();
+        }
+        if let Some(user_agent) = string("user_agent")? {
+            config.user_agent = user_agent;
+        }
+        if let Some(value) = document.get(None, "request_interval_secs") {
+            let secs = value
+                .as_integer()
+                .and_then(|secs| u64::try_from(secs).ok())
+                .ok_or_else(|| {
+                    AocError::Config(
+                        "Expected 'request_interval_secs' to be a non-negative integer".to_string(),
+                    )
+                })?;
+            config.request_interval = Duration::from_secs(secs);
+        }
+
+        Ok(config)
+    }
+
+    /// Load the config file, falling back to the defaults if there is none.
+    pub fn load(path: &std::path::Path) -> Result<Config, AocError> {
+        crate::load_or_default(path, Config::parse)
+    }
+
+    pub fn session(&self) -> Result<&str, AocError> {
+        self.session.as_deref().ok_or_else(|| {
+            AocError::Config(format!(
+                "No session token. Set 'session' in {} to the value of your session cookie",
+                Config::path_from_env().display()
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_overrides_defaults() {
+        let config = Config::parse(
+            "session = \"abc\"\nbase_url = \"http://localhost:8000/\"\nrequest_interval_secs = 0\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.session().unwrap(), "abc");
+        assert_eq!(config.base_url, "http://localhost:8000");
+        assert_eq!(config.request_interval, Duration::ZERO);
+        assert_eq!(config.user_agent, Config::default().user_agent);
+    }
+
+    #[test]
+    fn parse_rejects_wrong_types() {
+        assert!(Config::parse("session = 12\n").is_err());
+        assert!(Config::parse("request_interval_secs = -1\n").is_err());
+    }
+}
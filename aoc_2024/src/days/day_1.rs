@@ -0,0 +1,89 @@
+use crate::error::{parse_field, AocError};
+use crate::solution::Solution;
+
+pub struct Input {
+    lhs: Vec<u32>,
+    rhs: Vec<u32>,
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    const EXAMPLES: &'static [&'static str] = &["\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+"];
+
+    type Input = Input;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(raw_input: &str) -> Result<Input, AocError> {
+        let mut lhs: Vec<u32> = Vec::new();
+        let mut rhs: Vec<u32> = Vec::new();
+
+        for (line_index, line_string) in raw_input.lines().enumerate() {
+            let words: Vec<&str> = line_string.split_ascii_whitespace().collect();
+            if words.len() != 2 {
+                return Err(AocError::parse(
+                    line_index,
+                    1,
+                    format!("Expected two numbers, found {} words", words.len()),
+                ));
+            }
+
+            lhs.push(parse_field(line_index, line_string, words[0])?);
+            rhs.push(parse_field(line_index, line_string, words[1])?);
+        }
+        Ok(Input { lhs, rhs })
+    }
+
+    fn part_one(input: &Input) -> Result<u32, AocError> {
+        let mut lhs = input.lhs.clone();
+        let mut rhs = input.rhs.clone();
+        lhs.sort_unstable();
+        rhs.sort_unstable();
+
+        Ok(lhs
+            .iter()
+            .zip(rhs.iter())
+            .map(|tup| u32::abs_diff(*tup.0, *tup.1))
+            .sum())
+    }
+
+    fn part_two(input: &Input) -> Result<Option<u32>, AocError> {
+        let count_matches_in_rhs = |&val: &u32| -> u32 {
+            input
+                .rhs
+                .iter()
+                .filter(|&&rhs_val| rhs_val == val)
+                .count()
+                .try_into()
+                .unwrap()
+        };
+
+        let similarity_score: u32 = input
+            .lhs
+            .iter()
+            .map(|item| item * count_matches_in_rhs(item))
+            .sum();
+
+        Ok(Some(similarity_score))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day1;
+        part_one_example: (1, One, 11),
+        part_two_example: (1, Two, 31),
+    }
+}
@@ -0,0 +1,124 @@
+use crate::error::{parse_field, AocError};
+use crate::solution::Solution;
+
+struct Report {
+    levels: Vec<u32>,
+}
+
+pub struct Input {
+    reports: Vec<Report>,
+}
+
+fn is_safe(report: &Report) -> bool {
+    let diffs: Vec<i32> = report
+        .levels
+        .windows(2)
+        .map(|pair| pair[1] as i32 - pair[0] as i32)
+        .collect();
+
+    let sign_first_diff = diffs.first().unwrap().signum();
+    if sign_first_diff == 0 {
+        return false;
+    }
+
+    let is_monotonic = diffs.iter().all(|diff| diff.signum() == sign_first_diff);
+    let is_gradual = diffs.iter().all(|diff| 1 <= diff.abs() && diff.abs() <= 3);
+
+    is_monotonic && is_gradual
+}
+
+fn is_safe_if_allowed_to_remove_one_bad_level(report: &Report) -> bool {
+    if is_safe(report) {
+        return true;
+    }
+
+    let modified_reports: Vec<Report> = report
+        .levels
+        .iter()
+        .enumerate()
+        .map(|(index_to_remove, _)| Report {
+            levels: report
+                .levels
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| *index != index_to_remove)
+                .map(|(_, val)| *val)
+                .collect(),
+        })
+        .collect();
+
+    modified_reports.iter().any(is_safe)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    const EXAMPLES: &'static [&'static str] = &["\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+"];
+
+    type Input = Input;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(raw_input: &str) -> Result<Input, AocError> {
+        let read_report = |(line_index, line_string): (usize, &str)| -> Result<Report, AocError> {
+            let levels = line_string
+                .split_ascii_whitespace()
+                .map(|word| parse_field(line_index, line_string, word))
+                .collect::<Result<Vec<u32>, AocError>>()?;
+
+            // Safety is judged on the differences between levels, so there must be at least one
+            if levels.len() < 2 {
+                return Err(AocError::parse(
+                    line_index,
+                    1,
+                    "Expected a report with at least two levels",
+                ));
+            }
+            Ok(Report { levels })
+        };
+        Ok(Input {
+            reports: raw_input
+                .lines()
+                .enumerate()
+                .map(read_report)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn part_one(input: &Input) -> Result<usize, AocError> {
+        Ok(input
+            .reports
+            .iter()
+            .filter(|report| is_safe(report))
+            .count())
+    }
+
+    fn part_two(input: &Input) -> Result<Option<usize>, AocError> {
+        let number_of_safe_reports_now = input
+            .reports
+            .iter()
+            .filter(|report| is_safe_if_allowed_to_remove_one_bad_level(report))
+            .count();
+
+        Ok(Some(number_of_safe_reports_now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day2;
+        part_one_example: (1, One, 2),
+        part_two_example: (1, Two, 4),
+    }
+}
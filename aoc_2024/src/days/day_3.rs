@@ -0,0 +1,164 @@
+use std::fmt::Display;
+
+use crate::error::AocError;
+use crate::solution::Solution;
+use crate::tokenizer::{tokenize, Template};
+
+/// An instruction of the corrupted program. A new kind of instruction needs a variant here, a
+/// template in `INSTRUCTIONS` that builds it and an arm in `Machine::execute`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Statement {
+    Mul { lhs: u64, rhs: u64 },
+    Do,
+    Dont,
+}
+
+impl Display for Statement {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Statement::Mul { lhs, rhs } => write!(f, "mul({},{})", lhs, rhs),
+            Statement::Do => write!(f, "do()"),
+            Statement::Dont => write!(f, "don't()"),
+        }
+    }
+}
+
+pub struct ParsedStatement {
+    statement: Statement,
+    /// Byte offset of the statement in the input.
+    offset: usize,
+}
+
+/// Builds the statement from the arguments of its template.
+type BuildStatement = fn(&[u64]) -> Statement;
+
+const INSTRUCTIONS: [Template<BuildStatement>; 3] = [
+    Template::new(
+        |args| Statement::Mul {
+            lhs: args[0],
+            rhs: args[1],
+        },
+        "mul",
+        2,
+    ),
+    Template::new(|_| Statement::Do, "do", 0),
+    Template::new(|_| Statement::Dont, "don't", 0),
+];
+
+fn find_statements(raw_input: &str) -> Vec<ParsedStatement> {
+    tokenize(&INSTRUCTIONS, raw_input)
+        .map(|token| ParsedStatement {
+            statement: (token.kind)(&token.args),
+            offset: token.offset,
+        })
+        .collect()
+}
+
+/// State of the machine running the program.
+pub struct Machine {
+    /// Whether `do()` and `don't()` have an effect at all.
+    conditionals: bool,
+    enabled: bool,
+    accumulator: u64,
+    /// Print each statement as it is executed or skipped.
+    trace: bool,
+}
+
+impl Machine {
+    fn new(conditionals: bool) -> Machine {
+        Machine {
+            conditionals,
+            enabled: true,
+            accumulator: 0,
+            trace: crate::diagnostics::is_tracing(),
+        }
+    }
+
+    /// Execute a statement. Returns false if it was skipped.
+    fn execute(&mut self, statement: &Statement) -> bool {
+        match statement {
+            Statement::Mul { lhs, rhs } if self.enabled => self.accumulator += lhs * rhs,
+            Statement::Do if self.conditionals => self.enabled = true,
+            Statement::Dont if self.conditionals => self.enabled = false,
+            _ => return false,
+        }
+        true
+    }
+
+    fn run(mut self, statements: &[ParsedStatement]) -> u64 {
+        for parsed in statements {
+            let executed = self.execute(&parsed.statement);
+            if self.trace {
+                crate::diagnostic!(
+                    "{:>6}  {:<14} {:<8}  accumulator = {}",
+                    parsed.offset,
+                    parsed.statement.to_string(),
+                    if executed { "executed" } else { "skipped" },
+                    self.accumulator
+                );
+            }
+        }
+        self.accumulator
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    const EXAMPLES: &'static [&'static str] = &[
+        "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+",
+        "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+",
+    ];
+
+    type Input = Vec<ParsedStatement>;
+    type AnswerOne = u64;
+    type AnswerTwo = u64;
+
+    fn parse(raw_input: &str) -> Result<Vec<ParsedStatement>, AocError> {
+        Ok(find_statements(raw_input))
+    }
+
+    fn part_one(statements: &Vec<ParsedStatement>) -> Result<u64, AocError> {
+        // Execute all the mul statements, ignoring do() and don't()
+        Ok(Machine::new(false).run(statements))
+    }
+
+    fn part_two(statements: &Vec<ParsedStatement>) -> Result<Option<u64>, AocError> {
+        // Only the mul statements enabled by do() count
+        Ok(Some(Machine::new(true).run(statements)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day3;
+        part_one_example: (1, One, 161),
+        part_one_ignores_do_and_dont: (2, One, 161),
+        part_two_example: (2, Two, 48),
+        part_two_without_dont_counts_all: (1, Two, 161),
+    }
+
+    #[test]
+    fn machine_skips_statements_while_disabled() {
+        let statements = find_statements(Day3::EXAMPLES[1]);
+        let mut machine = Machine::new(true);
+
+        let executed: Vec<bool> = statements
+            .iter()
+            .map(|parsed| machine.execute(&parsed.statement))
+            .collect();
+        assert_eq!(executed, [true, true, false, false, true, true]);
+        assert_eq!(statements[1].offset, 20);
+        assert_eq!(statements[1].statement.to_string(), "don't()");
+        assert!(machine.enabled);
+        assert_eq!(machine.accumulator, 48);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::error::AocError;
+use crate::solution::Solution;
+use crate::stencil::{Orientation, Stencil};
+use crate::Array2D;
+
+/// Number of places where the stencil, given as rows of text with '.' as wildcard, matches in
+/// any orientation.
+fn count_matches(array: &Array2D<u8>, rows: &[&str]) -> usize {
+    let stencil = Stencil::from_rows(rows, '.', |ch| ch as u8);
+    array.find_stencil(&stencil, &Orientation::ALL).len()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    const EXAMPLES: &'static [&'static str] = &["\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+"];
+
+    type Input = Array2D<u8>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(raw_input: &str) -> Result<Array2D<u8>, AocError> {
+        raw_input.parse()
+    }
+
+    fn part_one(array: &Array2D<u8>) -> Result<usize, AocError> {
+        // XMAS along a row or a diagonal, in every direction
+        Ok(count_matches(array, &["XMAS"]) + count_matches(array, &["X", ".M", "..A", "...S"]))
+    }
+
+    fn part_two(array: &Array2D<u8>) -> Result<Option<usize>, AocError> {
+        // Two MAS crossing in an X, each of which can be written either way
+        Ok(Some(count_matches(array, &["M.S", ".A.", "M.S"])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day4;
+        part_one_example: (1, One, 18),
+        part_two_example: (1, Two, 9),
+    }
+}
@@ -0,0 +1,155 @@
+use crate::error::{parse_field, AocError};
+use crate::solution::Solution;
+
+struct Rule {
+    first: u32,
+    second: u32,
+}
+
+struct PageList {
+    pages: Vec<u32>,
+}
+
+impl PageList {
+    fn check_rule(&self, rule: &Rule) -> bool {
+        let first = self.pages.iter().position(|&page| page == rule.first);
+        let second = self.pages.iter().position(|&page| page == rule.second);
+
+        if first.is_none() || second.is_none() {
+            return true;
+        }
+        first.unwrap() < second.unwrap()
+    }
+
+    fn check_rules(&self, rules: &[Rule]) -> bool {
+        rules.iter().all(|rule| self.check_rule(rule))
+    }
+
+    fn middle_page(&self) -> u32 {
+        let middle_index = self.pages.len() / 2;
+        *self.pages.get(middle_index).unwrap()
+    }
+}
+
+pub struct Input {
+    rules: Vec<Rule>,
+    page_lists: Vec<PageList>,
+}
+
+fn read_input(raw_input: &str) -> Result<Input, AocError> {
+    let mut rules: Vec<Rule> = Vec::new();
+    let mut page_lists: Vec<PageList> = Vec::new();
+
+    let mut reading_rules = true;
+
+    for (line_index, line) in raw_input.lines().enumerate() {
+        if line.is_empty() {
+            // Empty line signifies end of rules and start of page lists
+            reading_rules = false;
+        } else if reading_rules {
+            // Parse rule
+            let numbers: Vec<u32> = line
+                .split('|')
+                .map(|field| parse_field(line_index, line, field))
+                .collect::<Result<_, _>>()?;
+            if numbers.len() != 2 {
+                return Err(AocError::parse(
+                    line_index,
+                    1,
+                    "Found rule with something different than two numbers.",
+                ));
+            }
+            rules.push(Rule {
+                first: *numbers.first().unwrap(),
+                second: *numbers.get(1).unwrap(),
+            });
+        } else {
+            // Parse page list
+            let numbers: Vec<u32> = line
+                .split(',')
+                .map(|field| parse_field(line_index, line, field))
+                .collect::<Result<_, _>>()?;
+            if numbers.len().is_multiple_of(2) {
+                return Err(AocError::parse(
+                    line_index,
+                    1,
+                    "Found even number of pages in a page list.",
+                ));
+            }
+            page_lists.push(PageList { pages: numbers });
+        }
+    }
+
+    Ok(Input { rules, page_lists })
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    const EXAMPLES: &'static [&'static str] = &["\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+"];
+
+    type Input = Input;
+    type AnswerOne = u32;
+    type AnswerTwo = crate::solution::Unsolved;
+
+    fn parse(raw_input: &str) -> Result<Input, AocError> {
+        let input = read_input(raw_input)?;
+
+        crate::diagnostic!(
+            "Found {} rules and {} page lists",
+            input.rules.len(),
+            input.page_lists.len()
+        );
+
+        Ok(input)
+    }
+
+    fn part_one(input: &Input) -> Result<u32, AocError> {
+        Ok(input
+            .page_lists
+            .iter()
+            .filter(|&page_list| page_list.check_rules(&input.rules))
+            .map(|page_list| page_list.middle_page())
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day5;
+        part_one_example: (1, One, 143),
+    }
+}
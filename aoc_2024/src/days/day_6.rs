@@ -0,0 +1,191 @@
+use crate::direction::Direction;
+use crate::error::AocError;
+use crate::solution::Solution;
+use crate::vec2::Vec2;
+use crate::Array2D;
+use std::collections::HashMap;
+
+#[derive(Clone)]
+struct Map {
+    grid: Array2D<u8>,
+}
+
+impl Map {
+    fn new(raw_input: &str) -> Result<Map, AocError> {
+        Ok(Map {
+            grid: raw_input.parse()?,
+        })
+    }
+
+    fn find(&self, target: u8) -> Option<Vec2> {
+        self.grid
+            .iter()
+            .find(|(_, &value)| value == target)
+            .map(|(pos, _)| pos)
+    }
+}
+
+#[derive(Clone)]
+struct Guard {
+    pos: Vec2,
+    dir: Direction,
+}
+
+#[derive(Clone)]
+pub struct Game {
+    map: Map,
+    guard: Guard,
+    char_to_dir_map: HashMap<u8, Direction>,
+    positions_visited: Vec<Vec2>,
+}
+
+impl Game {
+    // TODO(ESKIL): Don't store guard in map. Only store the static bits.
+    fn new(raw_input: &str) -> Result<Game, AocError> {
+        // Load initial map with all input including guard
+        let mut map = Map::new(raw_input)?;
+
+        // Set up mapping between guard char and direction
+        let mut char_to_dir_map: HashMap<u8, Direction> = HashMap::new();
+        char_to_dir_map.insert(b'>', Direction::Right);
+        char_to_dir_map.insert(b'v', Direction::Down);
+        char_to_dir_map.insert(b'<', Direction::Left);
+        char_to_dir_map.insert(b'^', Direction::Up);
+
+        let guards_found: Vec<Vec2> = char_to_dir_map
+            .keys()
+            .filter_map(|key| map.find(*key))
+            .collect();
+
+        if guards_found.len() != 1 {
+            return Err(AocError::InvalidInput(
+                "Expected exactly one matching guard character in raw input.".to_string(),
+            ));
+        }
+        let guard_initial_pos = guards_found.first().unwrap();
+
+        // Find initial orientation of guard
+        let guard_char = map.grid.get(*guard_initial_pos)?;
+        let guard_initial_dir = char_to_dir_map.get(&guard_char).unwrap();
+
+        // Create guard
+        let guard = Guard {
+            pos: *guard_initial_pos,
+            dir: *guard_initial_dir,
+        };
+
+        // Remove guard from map so it represents the static part
+        map.grid.set(*guard_initial_pos, b'.')?;
+
+        Ok(Game {
+            map,
+            guard,
+            char_to_dir_map,
+            positions_visited: Vec::new(),
+        })
+    }
+
+    fn update(&mut self) -> Result<bool, AocError> {
+        // Register current position
+        if !self.positions_visited.contains(&self.guard.pos) {
+            self.positions_visited.push(self.guard.pos);
+        }
+
+        // Attempt move
+        let new_guard_pos = self.guard.dir.step(self.guard.pos);
+        if !self.map.grid.in_bounds(new_guard_pos) {
+            // Moved outside of map
+            return Ok(false);
+        }
+        let new_pos_obj = self.map.grid.get(new_guard_pos)?;
+
+        if new_pos_obj == b'#' {
+            // Occuped: Turn clockwise
+            self.guard.dir = self.guard.dir.rotate_right();
+        } else if new_pos_obj == b'.' {
+            // Unoccupied: Move forward
+            self.guard.pos = new_guard_pos;
+        } else {
+            return Err(AocError::parse(
+                new_guard_pos.y as usize,
+                new_guard_pos.x as usize + 1,
+                format!("Unexpected symbol '{}' on map", new_pos_obj as char),
+            ));
+        }
+
+        Ok(true)
+    }
+}
+
+impl std::fmt::Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for row in 0..self.map.grid.height as i64 {
+            for col in 0..self.map.grid.width as i64 {
+                let pos = Vec2::new(col, row);
+
+                if pos == self.guard.pos {
+                    let guard_char = self
+                        .char_to_dir_map
+                        .iter()
+                        .find(|(_, &val)| val == self.guard.dir)
+                        .map(|(key, _)| key)
+                        .unwrap();
+
+                    write!(f, "{}", *guard_char as char).unwrap();
+                } else {
+                    write!(f, "{}", self.map.grid[pos] as char).unwrap();
+                }
+            }
+            writeln!(f).unwrap();
+        }
+        Ok(())
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    const EXAMPLES: &'static [&'static str] = &["\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+"];
+
+    type Input = Game;
+    type AnswerOne = usize;
+    type AnswerTwo = crate::solution::Unsolved;
+
+    fn parse(raw_input: &str) -> Result<Game, AocError> {
+        let game = Game::new(raw_input)?;
+        crate::diagnostic!("{}", game);
+        Ok(game)
+    }
+
+    fn part_one(game: &Game) -> Result<usize, AocError> {
+        let mut game = game.clone();
+
+        while game.update()? {
+            game.update()?;
+        }
+
+        Ok(game.positions_visited.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day6;
+        part_one_example: (1, One, 41),
+    }
+}
@@ -0,0 +1,128 @@
+use std::cmp::Ordering;
+
+use crate::error::{parse_field, AocError};
+use crate::solution::Solution;
+
+struct EquationCandidate {
+    result: i64,
+    terms: Vec<i64>,
+}
+
+pub struct Input {
+    equations: Vec<EquationCandidate>,
+}
+
+fn read_input(raw_input: &str) -> Result<Input, AocError> {
+    let mut equations: Vec<EquationCandidate> = Vec::new();
+
+    for (line_index, line) in raw_input.lines().enumerate() {
+        let parts: Vec<&str> = line.split(":").collect();
+        if parts.len() != 2 {
+            return Err(AocError::parse(
+                line_index,
+                1,
+                "Expected exactly one ':' between result and terms",
+            ));
+        }
+        let result: i64 = parse_field(line_index, line, parts[0])?;
+        let terms: Vec<i64> = parts[1]
+            .split_ascii_whitespace()
+            .map(|field| parse_field(line_index, line, field))
+            .collect::<Result<_, _>>()?;
+        if terms.len() < 2 {
+            return Err(AocError::parse(
+                line_index,
+                parts[0].len() + 2,
+                "Expected at least two terms",
+            ));
+        }
+        equations.push(EquationCandidate { result, terms });
+    }
+
+    Ok(Input { equations })
+}
+
+fn apply_operations(terms: &[i64]) -> Vec<i64> {
+    // Define possible operations
+    let op1 = |lhs: i64, rhs: i64| lhs + rhs;
+    let op2 = |lhs: i64, rhs: i64| lhs * rhs;
+
+    // Last term is always rhs
+    let rhs = *terms.last().unwrap();
+
+    match terms.len().cmp(&2) {
+        Ordering::Equal => {
+            // Final two terms. Pass it up the stack.
+            let lhs = *terms.first().unwrap();
+            let res1 = op1(lhs, rhs);
+            let res2 = op2(lhs, rhs);
+            vec![res1, res2]
+        }
+        Ordering::Greater => {
+            // More than two terms. Apply all possible results from the left.
+            let mut answers: Vec<i64> = Vec::new();
+            let lhs_candidates = apply_operations(&terms[..terms.len() - 1]);
+            for lhs in lhs_candidates {
+                answers.push(op1(lhs, rhs));
+                answers.push(op2(lhs, rhs));
+            }
+            answers
+        }
+        Ordering::Less => {
+            panic!("Unexpected terms list of less than two items.");
+        }
+    }
+}
+
+fn check_equation(eq: &EquationCandidate) -> Option<i64> {
+    // Find all possible answers
+    let answers = apply_operations(&eq.terms);
+
+    // See if any of them match the desired answer
+    let can_be_solved = answers.contains(&eq.result);
+
+    if can_be_solved {
+        Some(eq.result)
+    } else {
+        None
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    const EXAMPLES: &'static [&'static str] = &["\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+"];
+
+    type Input = Input;
+    type AnswerOne = i64;
+    type AnswerTwo = crate::solution::Unsolved;
+
+    fn parse(raw_input: &str) -> Result<Input, AocError> {
+        read_input(raw_input)
+    }
+
+    fn part_one(input: &Input) -> Result<i64, AocError> {
+        Ok(input.equations.iter().filter_map(check_equation).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day7;
+        part_one_example: (1, One, 3749),
+    }
+}
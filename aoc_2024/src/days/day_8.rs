@@ -0,0 +1,72 @@
+use crate::error::AocError;
+use crate::solution::Solution;
+use crate::vec2::Vec2;
+use crate::Array2D;
+use std::collections::HashSet;
+
+fn find_antinodes_for_node(node_pos: Vec2, array2d: &Array2D<char>) -> Vec<Vec2> {
+    let node = array2d[node_pos];
+
+    array2d
+        .iter()
+        .filter(|&(pos, &value)| pos.x != node_pos.x && pos.y != node_pos.y && value == node)
+        // Found a matching antenna, the antinode is as far beyond it as it is from the node
+        .map(|(pos, _)| pos + (pos - node_pos))
+        // Only keep antinodes within the map
+        .filter(|&antinode| array2d.in_bounds(antinode))
+        .collect()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+    const EXAMPLES: &'static [&'static str] = &["\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+"];
+
+    type Input = Array2D<char>;
+    type AnswerOne = usize;
+    type AnswerTwo = crate::solution::Unsolved;
+
+    fn parse(raw_input: &str) -> Result<Array2D<char>, AocError> {
+        let array2d: Array2D<char> = raw_input.parse()?;
+        crate::diagnostic!("{}", array2d);
+        Ok(array2d)
+    }
+
+    fn part_one(array2d: &Array2D<char>) -> Result<usize, AocError> {
+        let antinodes: Vec<Vec2> = array2d
+            .iter()
+            .filter(|(_, value)| value.is_alphanumeric())
+            .flat_map(|(pos, _)| find_antinodes_for_node(pos, array2d))
+            .collect();
+
+        for antinode in &antinodes {
+            crate::diagnostic!("{},{}", antinode.y, antinode.x);
+        }
+
+        Ok(HashSet::<_>::from_iter(antinodes).len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day8;
+        part_one_example: (1, One, 14),
+    }
+}
@@ -0,0 +1,180 @@
+use crate::error::AocError;
+use crate::solution::Solution;
+
+pub struct Input {
+    dense_layout: Vec<u8>,
+}
+
+fn read_input(raw_input: &str) -> Result<Input, AocError> {
+    let parse_digit = |(offset, ch): (usize, char)| -> Result<u8, AocError> {
+        match ch.to_digit(10) {
+            Some(digit) => Ok(digit as u8),
+            None => Err(AocError::parse_at_offset(
+                raw_input,
+                offset,
+                format!("Expected a digit, found '{}'", ch),
+            )),
+        }
+    };
+    Ok(Input {
+        dense_layout: raw_input
+            .trim_end()
+            .char_indices()
+            .map(parse_digit)
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+enum BlockType {
+    File,
+    Free,
+}
+
+struct Disk {
+    blocks: Vec<Option<u32>>,
+}
+
+impl Disk {
+    fn out_of_bounds(&self, idx: usize) -> AocError {
+        AocError::OutOfBounds {
+            index: vec![idx as i64],
+            shape: vec![self.blocks.len()],
+        }
+    }
+
+    fn move_block(&mut self, src_idx: usize, dst_idx: usize) -> Result<(), AocError> {
+        // Src must be a file block
+        let src_value = match self.blocks.get(src_idx) {
+            Some(optval) => match optval {
+                Some(val) => val,
+                None => {
+                    return Err(AocError::InvalidInput(
+                        "Cannot move from block. Block is already empty.".to_string(),
+                    ))
+                }
+            },
+            None => return Err(self.out_of_bounds(src_idx)),
+        };
+        // Dst must be an empty block
+        if let Some(optval) = self.blocks.get(dst_idx) {
+            if optval.is_some() {
+                return Err(AocError::InvalidInput(
+                    "Cannot move to a non-empty block".to_string(),
+                ));
+            }
+        } else {
+            return Err(self.out_of_bounds(dst_idx));
+        }
+
+        // Perform the move
+        self.blocks[dst_idx] = Some(*src_value);
+        self.blocks[src_idx] = None;
+
+        Ok(())
+    }
+
+    fn idx_last_used_block(&self) -> Option<usize> {
+        for (inv_idx, optval) in self.blocks.iter().rev().enumerate() {
+            if optval.is_some() {
+                return Some((self.blocks.len().checked_sub(inv_idx + 1)).unwrap());
+            }
+        }
+        None
+    }
+
+    fn idx_first_empty_block(&self) -> Option<usize> {
+        for (idx, optval) in self.blocks.iter().enumerate() {
+            match optval {
+                Some(_) => {}
+                None => return Some(idx),
+            }
+        }
+        None
+    }
+
+    fn compute_checksum(&self) -> usize {
+        self.blocks
+            .iter()
+            .enumerate()
+            .map(|(idx, optval)| idx * optval.unwrap_or(0) as usize)
+            .sum()
+    }
+}
+
+impl std::fmt::Display for Disk {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for optval in &self.blocks {
+            match optval {
+                Some(val) => write!(f, "|{}", val).unwrap(),
+                None => write!(f, "|.").unwrap(),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn decompress(input: &Input) -> Disk {
+    let mut file_id = 0_u32;
+    let mut block_type = BlockType::File;
+    let mut full_layout: Vec<Option<u32>> = Vec::new();
+    for &num in &input.dense_layout {
+        match block_type {
+            BlockType::File => {
+                let new_block = std::iter::repeat_n(file_id, num as usize);
+                full_layout.extend(new_block.into_iter().map(Some));
+                file_id += 1;
+                block_type = BlockType::Free;
+            }
+            BlockType::Free => {
+                let new_block = std::iter::repeat_n(None, num as usize);
+                full_layout.extend(new_block);
+                block_type = BlockType::File;
+            }
+        }
+    }
+    Disk {
+        blocks: full_layout,
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+    const EXAMPLES: &'static [&'static str] = &["\
+2333133121414131402
+"];
+
+    type Input = Input;
+    type AnswerOne = usize;
+    type AnswerTwo = crate::solution::Unsolved;
+
+    fn parse(raw_input: &str) -> Result<Input, AocError> {
+        read_input(raw_input)
+    }
+
+    fn part_one(input: &Input) -> Result<usize, AocError> {
+        let mut disk = decompress(input);
+
+        while let Some((src_idx, dst_idx)) =
+            match (disk.idx_last_used_block(), disk.idx_first_empty_block()) {
+                (Some(src_idx), Some(dst_idx)) if dst_idx < src_idx => Some((src_idx, dst_idx)),
+                _ => None,
+            }
+        {
+            disk.move_block(src_idx, dst_idx)?;
+        }
+
+        Ok(disk.compute_checksum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day9;
+        part_one_example: (1, One, 1928),
+    }
+}
@@ -0,0 +1,117 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static TRACING: AtomicBool = AtomicBool::new(false);
+
+/// Where diagnostics go on a thread.
+enum Sink {
+    Print,
+    Capture(Vec<String>),
+    Discard,
+}
+
+thread_local! {
+    static SINK: RefCell<Sink> = const { RefCell::new(Sink::Print) };
+}
+
+/// Ask the solutions that support it to emit a trace of each step they take.
+pub fn set_tracing(tracing: bool) {
+    TRACING.store(tracing, Ordering::Relaxed);
+}
+
+pub fn is_tracing() -> bool {
+    TRACING.load(Ordering::Relaxed)
+}
+
+/// Whether diagnostics emitted on this thread go anywhere. `diagnostic!` checks this before
+/// formatting its message, so discarded diagnostics cost nothing to build.
+pub fn is_enabled() -> bool {
+    SINK.with(|sink| !matches!(*sink.borrow(), Sink::Discard))
+}
+
+/// Hand a diagnostic to the innermost `capture` on this thread, or print it if there is none.
+pub fn emit(message: String) {
+    let uncaptured = SINK.with(|sink| match &mut *sink.borrow_mut() {
+        Sink::Print => Some(message),
+        Sink::Capture(messages) => {
+            messages.push(message);
+            None
+        }
+        Sink::Discard => None,
+    });
+    if let Some(message) = uncaptured {
+        println!("{}", message);
+    }
+}
+
+/// Run `f` and collect the diagnostics it emits instead of printing them. Inside `discard`,
+/// nothing is collected.
+pub fn capture<R>(f: impl FnOnce() -> R) -> (R, Vec<String>) {
+    if !is_enabled() {
+        return (f(), Vec::new());
+    }
+    let outer = SINK.with(|sink| sink.replace(Sink::Capture(Vec::new())));
+    let result = f();
+    match SINK.with(|sink| sink.replace(outer)) {
+        Sink::Capture(messages) => (result, messages),
+        _ => (result, Vec::new()),
+    }
+}
+
+/// Run `f` without building any of the diagnostics it emits, for when they would not be shown
+/// or would skew timings.
+pub fn discard<R>(f: impl FnOnce() -> R) -> R {
+    let outer = SINK.with(|sink| sink.replace(Sink::Discard));
+    let result = f();
+    SINK.with(|sink| sink.replace(outer));
+    result
+}
+
+/// Like `println!`, but for informational output from the solutions. While solving, it is
+/// collected with the answers so the runner can print it, put it in JSON or leave it out.
+#[macro_export]
+macro_rules! diagnostic {
+    ($($arg:tt)*) => {
+        if $crate::diagnostics::is_enabled() {
+            $crate::diagnostics::emit(format!($($arg)*))
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capture_collects_nested_diagnostics_separately() {
+        let ((inner, count), outer) = capture(|| {
+            crate::diagnostic!("outer {}", 1);
+            let (count, inner) = capture(|| {
+                crate::diagnostic!("inner");
+                2
+            });
+            crate::diagnostic!("outer {}", count);
+            (inner, count)
+        });
+
+        assert_eq!(count, 2);
+        assert_eq!(inner, vec!["inner"]);
+        assert_eq!(outer, vec!["outer 1", "outer 2"]);
+    }
+
+    #[test]
+    fn discard_skips_formatting_even_inside_capture() {
+        struct Panics;
+        impl std::fmt::Display for Panics {
+            fn fmt(&self, _: &mut std::fmt::Formatter) -> std::fmt::Result {
+                panic!("Formatted a discarded diagnostic")
+            }
+        }
+
+        let (_, messages) = capture(|| {
+            discard(|| capture(|| crate::diagnostic!("{}", Panics)));
+            crate::diagnostic!("kept");
+        });
+        assert_eq!(messages, vec!["kept"]);
+    }
+}
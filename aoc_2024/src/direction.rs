@@ -0,0 +1,98 @@
+use crate::vec2::Vec2;
+
+/// A direction on a grid where rows grow downwards.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The four directions along rows and columns, clockwise from up.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// All eight directions, clockwise from up.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// The change in position of one step in the direction.
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction::Up => Vec2::new(0, -1),
+            Direction::UpRight => Vec2::new(1, -1),
+            Direction::Right => Vec2::new(1, 0),
+            Direction::DownRight => Vec2::new(1, 1),
+            Direction::Down => Vec2::new(0, 1),
+            Direction::DownLeft => Vec2::new(-1, 1),
+            Direction::Left => Vec2::new(-1, 0),
+            Direction::UpLeft => Vec2::new(-1, -1),
+        }
+    }
+
+    /// The position one step away in the direction.
+    pub fn step(self, pos: Vec2) -> Vec2 {
+        pos + self.offset()
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        !Direction::CARDINAL.contains(&self)
+    }
+
+    /// Turned by the given number of eighths of a full turn clockwise.
+    fn turned(self, eighths: usize) -> Direction {
+        let idx = Direction::ALL.iter().position(|&dir| dir == self).unwrap();
+        Direction::ALL[(idx + eighths) % 8]
+    }
+
+    /// A quarter turn clockwise.
+    pub fn rotate_right(self) -> Direction {
+        self.turned(2)
+    }
+
+    /// A quarter turn counterclockwise.
+    pub fn rotate_left(self) -> Direction {
+        self.turned(6)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turned(4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotations_and_opposites() {
+        assert_eq!(Direction::Up.rotate_right(), Direction::Right);
+        assert_eq!(Direction::Up.rotate_left(), Direction::Left);
+        assert_eq!(Direction::UpLeft.rotate_right(), Direction::UpRight);
+        assert_eq!(Direction::DownRight.opposite(), Direction::UpLeft);
+        for dir in Direction::ALL {
+            let offset = dir.offset();
+            assert_eq!(dir.opposite().offset(), -offset);
+            assert_eq!(dir.rotate_left().rotate_right(), dir);
+            assert_eq!(dir.is_diagonal(), offset.x != 0 && offset.y != 0);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub enum AocError {
+    Io(std::io::Error),
+    /// Line and column are 1-based, like in an editor.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    OutOfBounds {
+        index: Vec<i64>,
+        shape: Vec<usize>,
+    },
+    InvalidInput(String),
+    /// The input cache has no input for the day.
+    MissingInput {
+        day: u32,
+        path: std::path::PathBuf,
+    },
+    Config(String),
+    /// The website answered with an error status.
+    Http {
+        status: u16,
+        message: String,
+    },
+}
+
+impl AocError {
+    /// Parse error for the given 0-based line index and 1-based column.
+    pub fn parse(line_index: usize, column: usize, message: impl Into<String>) -> AocError {
+        AocError::Parse {
+            line: line_index + 1,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Parse error at a byte offset into the whole input.
+    pub fn parse_at_offset(raw_input: &str, offset: usize, message: impl Into<String>) -> AocError {
+        let before = &raw_input[..offset];
+        let line_index = before.matches('\n').count();
+        let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+        AocError::parse(line_index, offset - line_start + 1, message)
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AocError::Io(err) => write!(f, "I/O error: {}", err),
+            AocError::Parse {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "Parse error at line {}, column {}: {}",
+                line, column, message
+            ),
+            AocError::OutOfBounds { index, shape } => {
+                write!(f, "Index {:?} incompatible with shape {:?}", index, shape)
+            }
+            AocError::InvalidInput(message) => write!(f, "Invalid input: {}", message),
+            AocError::MissingInput { day, path } => write!(
+                f,
+                "No input for day {} at {}. Store one with 'aoc input import <file> --day {}'",
+                day,
+                path.display(),
+                day
+            ),
+            AocError::Config(message) => write!(f, "Config error: {}", message),
+            AocError::Http { status, message } => {
+                write!(f, "HTTP error {}: {}", status, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for AocError {
+    fn from(err: std::io::Error) -> AocError {
+        AocError::Io(err)
+    }
+}
+
+/// Parse `field`, which must be a slice of `line`, reporting its position if it fails.
+pub fn parse_field<T>(line_index: usize, line: &str, field: &str) -> Result<T, AocError>
+where
+    T: std::str::FromStr,
+    T::Err: Display,
+{
+    field.parse().map_err(|err| {
+        let column = field.as_ptr() as usize - line.as_ptr() as usize + 1;
+        AocError::parse(
+            line_index,
+            column,
+            format!("Failed to parse '{}': {}", field, err),
+        )
+    })
+}
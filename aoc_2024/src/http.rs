@@ -0,0 +1,339 @@
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::process::Stdio;
+
+use crate::error::AocError;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+impl Method {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Everything that talks to the website goes through this, so tests can swap in a local server.
+pub trait HttpClient {
+    fn send(&self, request: &HttpRequest) -> Result<HttpResponse, AocError>;
+}
+
+fn http_error(message: impl Into<String>) -> AocError {
+    AocError::Io(std::io::Error::other(message.into()))
+}
+
+/// A line break in a header would end it early and let the rest pass as headers of its own.
+fn check_header(name: &str, value: &str) -> Result<(), AocError> {
+    if name.contains(['\r', '\n']) || value.contains(['\r', '\n']) {
+        return Err(http_error(format!("Invalid header: {}", name)));
+    }
+    Ok(())
+}
+
+/// Minimal HTTP/1.1 client over a plain TCP connection. It only speaks `http://`, so it is meant
+/// for local servers such as the stand-in server used by the tests.
+pub struct TcpHttpClient;
+
+impl HttpClient for TcpHttpClient {
+    fn send(&self, request: &HttpRequest) -> Result<HttpResponse, AocError> {
+        let rest = request
+            .url
+            .strip_prefix("http://")
+            .ok_or_else(|| http_error(format!("Only http:// is supported: {}", request.url)))?;
+        let (host, path) = match rest.find('/') {
+            Some(idx) => (&rest[..idx], &rest[idx..]),
+            None => (rest, "/"),
+        };
+
+        let mut message = format!(
+            "{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n",
+            request.method.as_str(),
+            path,
+            host
+        );
+        for (name, value) in &request.headers {
+            check_header(name, value)?;
+            message.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        let body = request.body.as_deref().unwrap_or("");
+        message.push_str(&format!("Content-Length: {}\r\n\r\n{}", body.len(), body));
+
+        let mut stream = TcpStream::connect(host)?;
+        stream.write_all(message.as_bytes())?;
+        let mut raw_response = String::new();
+        stream.read_to_string(&mut raw_response)?;
+
+        let (head, body) = raw_response
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| http_error("Malformed HTTP response"))?;
+        let status = head
+            .split_ascii_whitespace()
+            .nth(1)
+            .and_then(|status| status.parse().ok())
+            .ok_or_else(|| http_error("Malformed HTTP status line"))?;
+
+        Ok(HttpResponse {
+            status,
+            body: body.to_string(),
+        })
+    }
+}
+
+/// Client that runs the `curl` command, which takes care of TLS for `https://`.
+pub struct CurlHttpClient;
+
+impl HttpClient for CurlHttpClient {
+    fn send(&self, request: &HttpRequest) -> Result<HttpResponse, AocError> {
+        // The headers hold the session cookie, so they go to curl on stdin rather than as
+        // arguments that other users can see in the process list
+        let mut headers = String::new();
+        for (name, value) in &request.headers {
+            check_header(name, value)?;
+            headers.push_str(&format!("{}: {}\n", name, value));
+        }
+
+        let mut command = std::process::Command::new("curl");
+        command
+            .args(["--silent", "--show-error", "--header", "@-", "--request"])
+            .arg(request.method.as_str());
+        if let Some(body) = &request.body {
+            command.arg("--data-raw").arg(body);
+        }
+        // Put the status code on a line of its own after the body
+        command
+            .args(["--write-out", "\n%{http_code}"])
+            .arg(&request.url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let mut child = command.spawn()?;
+        let mut stdin = child.stdin.take().unwrap();
+        stdin.write_all(headers.as_bytes())?;
+        drop(stdin);
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            return Err(http_error(format!(
+                "curl failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = stdout
+            .rsplit_once('\n')
+            .ok_or_else(|| http_error("Malformed curl output"))?;
+        let status = status
+            .trim()
+            .parse()
+            .map_err(|_| http_error("Malformed curl output"))?;
+
+        Ok(HttpResponse {
+            status,
+            body: body.to_string(),
+        })
+    }
+}
+
+/// The client to use for a base URL.
+pub fn client_for(base_url: &str) -> Box<dyn HttpClient> {
+    if base_url.starts_with("http://") {
+        Box::new(TcpHttpClient)
+    } else {
+        Box::new(CurlHttpClient)
+    }
+}
+
+/// A local HTTP server that answers requests with a handler, so the website can be stood in for
+/// without touching the network.
+#[cfg(test)]
+pub(crate) mod stand_in {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Debug)]
+    pub(crate) struct ReceivedRequest {
+        pub method: String,
+        pub path: String,
+        pub headers: Vec<(String, String)>,
+        pub body: String,
+    }
+
+    impl ReceivedRequest {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    type Handler = dyn Fn(&ReceivedRequest) -> (u16, String) + Send + Sync;
+
+    pub(crate) struct StandInServer {
+        pub base_url: String,
+        pub requests: Arc<Mutex<Vec<ReceivedRequest>>>,
+        stop: Arc<AtomicBool>,
+    }
+
+    fn read_request(stream: &mut TcpStream) -> ReceivedRequest {
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let mut words = line.split_ascii_whitespace();
+        let method = words.next().unwrap_or("").to_string();
+        let path = words.next().unwrap_or("").to_string();
+
+        let mut headers = Vec::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                headers.push((name.trim().to_string(), value.trim().to_string()));
+            }
+        }
+
+        let mut request = ReceivedRequest {
+            method,
+            path,
+            headers,
+            body: String::new(),
+        };
+        let length: usize = request
+            .header("Content-Length")
+            .and_then(|length| length.parse().ok())
+            .unwrap_or(0);
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+        request.body = String::from_utf8(body).unwrap();
+        request
+    }
+
+    impl StandInServer {
+        pub fn start(
+            handler: impl Fn(&ReceivedRequest) -> (u16, String) + Send + Sync + 'static,
+        ) -> StandInServer {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let base_url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let stop = Arc::new(AtomicBool::new(false));
+
+            let handler: Box<Handler> = Box::new(handler);
+            let thread_requests = Arc::clone(&requests);
+            let thread_stop = Arc::clone(&stop);
+            std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if thread_stop.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    let mut stream = stream.unwrap();
+                    let request = read_request(&mut stream);
+                    let (status, body) = handler(&request);
+                    thread_requests.lock().unwrap().push(request);
+                    write!(
+                        stream,
+                        "HTTP/1.1 {} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                }
+            });
+
+            StandInServer {
+                base_url,
+                requests,
+                stop,
+            }
+        }
+
+        pub fn received(&self) -> Vec<ReceivedRequest> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    impl Drop for StandInServer {
+        fn drop(&mut self) {
+            // Wake the server thread up so it sees the stop flag
+            self.stop.store(true, Ordering::SeqCst);
+            let _ = TcpStream::connect(self.base_url.trim_start_matches("http://"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::stand_in::StandInServer;
+    use super::*;
+
+    #[test]
+    fn curl_sends_headers_and_body() {
+        // Not every machine that runs the tests has curl
+        let has_curl = std::process::Command::new("curl")
+            .arg("--version")
+            .output()
+            .is_ok_and(|output| output.status.success());
+        if !has_curl {
+            return;
+        }
+        let server = StandInServer::start(|_| (200, "ok".to_string()));
+        let request = HttpRequest {
+            method: Method::Post,
+            url: format!("{}/2024/day/1/answer", server.base_url),
+            headers: vec![("Cookie".to_string(), "session=secret".to_string())],
+            body: Some("level=1&answer=42".to_string()),
+        };
+
+        let response = CurlHttpClient.send(&request).unwrap();
+        assert_eq!((response.status, response.body.as_str()), (200, "ok"));
+        let received = server.received();
+        assert_eq!(received[0].header("Cookie"), Some("session=secret"));
+        assert_eq!(received[0].body, "level=1&answer=42");
+
+        let injected = HttpRequest {
+            headers: vec![("Cookie".to_string(), "a\r\nX-Other: b".to_string())],
+            ..request
+        };
+        assert!(CurlHttpClient.send(&injected).is_err());
+    }
+    #[test]
+    fn tcp_client_rejects_line_breaks_in_headers() {
+        let server = StandInServer::start(|_| (200, "ok".to_string()));
+        let request = HttpRequest {
+            method: Method::Get,
+            url: format!("{}/2024/day/1/input", server.base_url),
+            headers: vec![("Cookie".to_string(), "a\r\nX-Other: b".to_string())],
+            body: None,
+        };
+
+        assert!(TcpHttpClient.send(&request).is_err());
+        assert!(server.received().is_empty());
+    }
+}
@@ -0,0 +1,71 @@
+use std::path::{Path, PathBuf};
+
+use crate::error::AocError;
+use crate::solution::Day;
+
+pub const YEAR: u32 = 2024;
+
+/// Environment variable that overrides where the input cache lives.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// Puzzle inputs stored as `<root>/<year>/day_<DD>.txt`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InputCache {
+    root: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(root: impl Into<PathBuf>) -> InputCache {
+        InputCache { root: root.into() }
+    }
+
+    /// The cache in `$AOC_INPUT_DIR`, or in `inputs` in the crate directory if it is not set.
+    pub fn from_env() -> InputCache {
+        InputCache::new(crate::env_or_crate_path(INPUT_DIR_VAR, DEFAULT_INPUT_DIR))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn path(&self, day: u32) -> PathBuf {
+        self.root
+            .join(YEAR.to_string())
+            .join(format!("day_{:02}.txt", day))
+    }
+
+    pub fn contains(&self, day: u32) -> bool {
+        self.path(day).is_file()
+    }
+
+    pub fn read(&self, day: u32) -> Result<String, AocError> {
+        let path = self.path(day);
+        match std::fs::read_to_string(&path) {
+            Ok(raw_input) => Ok(raw_input),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                Err(AocError::MissingInput { day, path })
+            }
+            Err(err) => Err(AocError::Io(err)),
+        }
+    }
+
+    /// Check that the input parses for the day, then store it. Returns where it was stored.
+    pub fn import(&self, day: &Day, raw_input: &str) -> Result<PathBuf, AocError> {
+        if raw_input.trim().is_empty() {
+            return Err(AocError::InvalidInput("Input is empty".to_string()));
+        }
+        // Solving no parts only parses the input
+        (day.solve)(raw_input, &[])?;
+
+        self.store(day.number, raw_input)
+    }
+
+    /// Store an input as is. Returns where it was stored.
+    pub fn store(&self, day: u32, raw_input: &str) -> Result<PathBuf, AocError> {
+        let path = self.path(day);
+        crate::save(&path, raw_input)?;
+        Ok(path)
+    }
+}
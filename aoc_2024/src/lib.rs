@@ -1,65 +1,67 @@
-use std::fmt::Display;
+pub mod answers;
+pub mod array2d;
+pub mod bench;
+pub mod cli;
+pub mod config;
+pub mod days;
+pub mod diagnostics;
+pub mod direction;
+pub mod error;
+pub mod http;
+pub mod inputs;
+pub mod region;
+pub mod remote;
+pub mod search;
+pub mod solution;
+pub mod sparse_grid;
+pub mod stencil;
+pub mod tokenizer;
+pub mod toml;
+pub mod vec2;
 
-pub fn get_single_path_as_arg() -> std::path::PathBuf {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() != 2 {
-        panic!("Expected exactly one argument.")
-    }
-    std::path::PathBuf::from(args.get(1).expect("Failed to get first argument"))
-        .canonicalize()
-        .expect("Failed to parse path")
-}
+pub use array2d::Array2D;
+pub use sparse_grid::SparseGrid;
 
-pub struct Array2D<T: Copy> {
-    pub height: usize,
-    pub width: usize,
-    data: Vec<T>,
+/// A path inside the crate directory, where the default config, inputs, answers and benchmark
+/// history live. Resolving them here rather than against the current directory keeps them in
+/// the places that `.gitignore` expects, wherever the binaries are run from.
+pub(crate) fn crate_path(relative: &str) -> std::path::PathBuf {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(relative)
 }
 
-impl<T: Copy> Array2D<T> {
-    pub fn new(height: usize, width: usize, init_value: T) -> Array2D<T> {
-        let size: usize = height.checked_mul(width).unwrap();
-
-        let data: Vec<T> = std::iter::repeat(init_value).take(size).collect();
-        Array2D {
-            height,
-            width,
-            data,
-        }
-    }
-
-    fn idx1d(&self, row: i64, col: i64) -> Result<usize, String> {
-        if row >= 0 && row < self.height as i64 && col >= 0 && col < self.width as i64 {
-            let idx: usize = (row * self.width as i64 + col).try_into().unwrap();
-            Ok(idx)
-        } else {
-            Err(format!(
-                "Index [{},{}] incompatible with size [0-{},0-{}]",
-                row, col, self.height, self.width
-            ))
-        }
-    }
+/// The path in the environment variable, or `default` in the crate directory if it is not set.
+pub(crate) fn env_or_crate_path(var: &str, default: &str) -> std::path::PathBuf {
+    std::env::var_os(var)
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| crate_path(default))
+}
 
-    pub fn set(&mut self, row: i64, col: i64, value: T) -> Result<(), String> {
-        let idx = self.idx1d(row, col)?;
-        self.data[idx] = value;
-        Ok(())
+/// Parse the file, or start from the default if there is none yet.
+pub(crate) fn load_or_default<T: Default>(
+    path: &std::path::Path,
+    parse: impl FnOnce(&str) -> Result<T, error::AocError>,
+) -> Result<T, error::AocError> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => parse(&text),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(T::default()),
+        Err(err) => Err(error::AocError::Io(err)),
     }
+}
 
-    pub fn get(&self, row: i64, col: i64) -> Result<T, String> {
-        let idx = self.idx1d(row, col)?;
-        Ok(self.data[idx])
+/// Write the file, creating the directories it goes in.
+pub(crate) fn save(path: &std::path::Path, contents: &str) -> Result<(), error::AocError> {
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)?;
     }
+    std::fs::write(path, contents)?;
+    Ok(())
 }
 
-impl<T: Copy + Display> std::fmt::Display for Array2D<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        for row in 0..self.height as i64 {
-            for col in 0..self.width as i64 {
-                write!(f, "{}", self.get(row, col).unwrap()).unwrap();
-            }
-            writeln!(f).unwrap();
-        }
-        Ok(())
-    }
+/// A fresh, empty directory for a test to write files in.
+#[cfg(test)]
+pub(crate) fn test_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc_2024_test_{}_{}", std::process::id(), name));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
 }
@@ -0,0 +1,208 @@
+//! Flood fill and connected regions of an `Array2D`, for puzzles about plots, areas and fences.
+
+use crate::direction::Direction;
+use crate::search::bfs;
+use crate::vec2::Vec2;
+use crate::Array2D;
+
+/// Which cells count as next to each other.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    /// Cells that share an edge.
+    Four,
+    /// Cells that share an edge or a corner.
+    Eight,
+}
+
+impl Connectivity {
+    fn directions(self) -> &'static [Direction] {
+        match self {
+            Connectivity::Four => &Direction::CARDINAL,
+            Connectivity::Eight => &Direction::ALL,
+        }
+    }
+}
+
+/// A set of connected cells.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Region {
+    /// The cells of the region, row by row.
+    cells: Vec<Vec2>,
+}
+
+impl Region {
+    fn new(mut cells: Vec<Vec2>) -> Region {
+        cells.sort();
+        Region { cells }
+    }
+
+    pub fn cells(&self) -> &[Vec2] {
+        &self.cells
+    }
+
+    pub fn contains(&self, pos: Vec2) -> bool {
+        self.cells.binary_search(&pos).is_ok()
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The number of cell edges between the region and the cells outside of it. Only edges
+    /// count, even if the region was found with `Connectivity::Eight`.
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .flat_map(|&pos| Direction::CARDINAL.map(|dir| dir.step(pos)))
+            .filter(|&pos| !self.contains(pos))
+            .count()
+    }
+
+    /// The number of corners on the outline of the region, inside and out. This is also the
+    /// number of straight sides, since every side ends in a corner.
+    pub fn corners(&self) -> usize {
+        let mut corners = 0;
+        for &pos in &self.cells {
+            for dir in Direction::CARDINAL {
+                let next = dir.rotate_right();
+                let (a, b) = (self.contains(dir.step(pos)), self.contains(next.step(pos)));
+                let diagonal = self.contains(pos + dir.offset() + next.offset());
+                // Outside corners have neither neighbor, inside corners both but not the cell
+                // between them
+                if (!a && !b) || (a && b && !diagonal) {
+                    corners += 1;
+                }
+            }
+        }
+        corners
+    }
+
+    pub fn sides(&self) -> usize {
+        self.corners()
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle holding the region, or
+    /// `None` if the region is empty.
+    pub fn bounds(&self) -> Option<(Vec2, Vec2)> {
+        let first = *self.cells.first()?;
+        let min = self.cells.iter().fold(first, |min, pos| {
+            Vec2::new(min.x.min(pos.x), min.y.min(pos.y))
+        });
+        let max = self.cells.iter().fold(first, |max, pos| {
+            Vec2::new(max.x.max(pos.x), max.y.max(pos.y))
+        });
+        Some((min, max))
+    }
+}
+
+impl<T> Array2D<T> {
+    fn connected<'a>(
+        &'a self,
+        pos: Vec2,
+        connectivity: Connectivity,
+        same: &'a impl Fn(&T, &T) -> bool,
+    ) -> impl Iterator<Item = Vec2> + 'a {
+        connectivity
+            .directions()
+            .iter()
+            .map(move |dir| dir.step(pos))
+            .filter(move |&next| self.in_bounds(next) && same(&self[pos], &self[next]))
+    }
+
+    /// The region of cells that can be reached from `seed` by stepping between neighbors for
+    /// which `same` is true. Regions never reach past the edge of the grid, even if its
+    /// boundary is `Wrap`, so that they measure the same as on paper. A seed outside the grid
+    /// gives an empty region.
+    pub fn flood_fill(
+        &self,
+        seed: Vec2,
+        connectivity: Connectivity,
+        same: impl Fn(&T, &T) -> bool,
+    ) -> Region {
+        if !self.in_bounds(seed) {
+            return Region::new(Vec::new());
+        }
+        let searched = bfs(
+            seed,
+            |&pos| self.connected(pos, connectivity, &same),
+            |_| false,
+        );
+        Region::new(searched.costs.into_keys().collect())
+    }
+
+    /// Every region of the grid, in the order of their first cells row by row, along with a
+    /// grid giving the index of the region each cell belongs to.
+    pub fn regions(
+        &self,
+        connectivity: Connectivity,
+        same: impl Fn(&T, &T) -> bool,
+    ) -> (Array2D<usize>, Vec<Region>) {
+        let mut labels = Array2D::new(self.height, self.width, usize::MAX);
+        let mut regions = Vec::new();
+        for (pos, _) in self.iter() {
+            if labels[pos] != usize::MAX {
+                continue;
+            }
+            let region = self.flood_fill(pos, connectivity, &same);
+            for &cell in region.cells() {
+                labels[cell] = regions.len();
+            }
+            regions.push(region);
+        }
+        (labels, regions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array2d::Boundary;
+
+    #[test]
+    fn regions_measure_area_perimeter_and_sides() {
+        let garden: Array2D<char> = "AAAA\nBBCD\nBBCC\nEEEC\n".parse().unwrap();
+        let (labels, regions) = garden.regions(Connectivity::Four, |a, b| a == b);
+
+        let measured: Vec<_> = regions
+            .iter()
+            .map(|region| (region.area(), region.perimeter(), region.sides()))
+            .collect();
+        assert_eq!(
+            measured,
+            [(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+        );
+        assert_eq!(labels[Vec2::new(3, 3)], 2);
+        assert_eq!(
+            regions[2].bounds(),
+            Some((Vec2::new(2, 1), Vec2::new(3, 3)))
+        );
+    }
+
+    #[test]
+    fn regions_stop_at_the_edge_of_the_grid() {
+        let array: Array2D<char> = "AAA\n".parse().unwrap();
+        let wrapping = array.clone().with_boundary(Boundary::Wrap);
+        let same = |a: &char, b: &char| a == b;
+
+        let (_, regions) = wrapping.regions(Connectivity::Four, same);
+        assert_eq!(regions, array.regions(Connectivity::Four, same).1);
+        assert_eq!((regions[0].perimeter(), regions[0].sides()), (8, 4));
+
+        let outside = wrapping.flood_fill(Vec2::new(5, 0), Connectivity::Four, same);
+        assert_eq!(outside.area(), 0);
+        assert_eq!(outside.bounds(), None);
+    }
+
+    #[test]
+    fn connectivity_decides_whether_corners_touch() {
+        let array: Array2D<char> = "X.\n.X\n".parse().unwrap();
+        let same = |a: &char, b: &char| a == b;
+
+        let four = array.flood_fill(Vec2::ZERO, Connectivity::Four, same);
+        let eight = array.flood_fill(Vec2::ZERO, Connectivity::Eight, same);
+        assert_eq!(four.cells(), [Vec2::ZERO]);
+        assert_eq!(eight.cells(), [Vec2::ZERO, Vec2::new(1, 1)]);
+        assert_eq!(eight.perimeter(), 8);
+        assert_eq!(array.regions(Connectivity::Eight, same).1.len(), 2);
+    }
+}
@@ -0,0 +1,393 @@
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::answers::{Ledger, Verdict};
+use crate::config::Config;
+use crate::error::AocError;
+use crate::http::{client_for, HttpClient, HttpRequest, HttpResponse, Method};
+use crate::inputs::{InputCache, YEAR};
+use crate::solution::Part;
+
+/// Spaces out requests to the website, also across separate runs, by remembering when the last
+/// request was sent in a stamp file.
+pub struct RateLimiter {
+    stamp_path: PathBuf,
+    interval: Duration,
+}
+
+impl RateLimiter {
+    pub fn new(stamp_path: impl Into<PathBuf>, interval: Duration) -> RateLimiter {
+        RateLimiter {
+            stamp_path: stamp_path.into(),
+            interval,
+        }
+    }
+
+    /// Sleep until the interval has passed since the last request, then record a new one.
+    pub fn wait(&self) -> Result<(), AocError> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+
+        let last = std::fs::read_to_string(&self.stamp_path)
+            .ok()
+            .and_then(|stamp| stamp.trim().parse().ok())
+            .map(Duration::from_millis);
+        if let Some(remaining) = last.and_then(|last| (last + self.interval).checked_sub(now)) {
+            std::thread::sleep(remaining);
+        }
+
+        if let Some(directory) = self.stamp_path.parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        std::fs::write(&self.stamp_path, now.as_millis().to_string())?;
+        Ok(())
+    }
+}
+
+/// The Advent of Code website, reached through an `HttpClient`.
+pub struct Remote {
+    client: Box<dyn HttpClient>,
+    config: Config,
+    rate_limiter: RateLimiter,
+}
+
+impl Remote {
+    pub fn new(client: Box<dyn HttpClient>, config: Config, rate_limiter: RateLimiter) -> Remote {
+        Remote {
+            client,
+            config,
+            rate_limiter,
+        }
+    }
+
+    /// Pick the client from the base URL and keep the rate limit stamp in the input cache.
+    pub fn from_config(config: Config, cache: &InputCache) -> Remote {
+        let rate_limiter =
+            RateLimiter::new(cache.root().join(".last_request"), config.request_interval);
+        Remote::new(client_for(&config.base_url), config, rate_limiter)
+    }
+
+    pub(crate) fn send(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<String>,
+    ) -> Result<HttpResponse, AocError> {
+        let mut headers = vec![
+            (
+                "Cookie".to_string(),
+                format!("session={}", self.config.session()?),
+            ),
+            ("User-Agent".to_string(), self.config.user_agent.clone()),
+        ];
+        if body.is_some() {
+            headers.push((
+                "Content-Type".to_string(),
+                "application/x-www-form-urlencoded".to_string(),
+            ));
+        }
+
+        self.rate_limiter.wait()?;
+        self.client.send(&HttpRequest {
+            method,
+            url: format!("{}{}", self.config.base_url, path),
+            headers,
+            body,
+        })
+    }
+
+    pub fn fetch_input(&self, day: u32) -> Result<String, AocError> {
+        let response = self.send(Method::Get, &format!("/{}/day/{}/input", YEAR, day), None)?;
+        check_status(day, response)
+    }
+
+    pub fn submit_answer(&self, day: u32, part: Part, answer: &str) -> Result<Verdict, AocError> {
+        let response = self.send(
+            Method::Post,
+            &format!("/{}/day/{}/answer", YEAR, day),
+            Some(format!("level={}&answer={}", part, form_encode(answer))),
+        )?;
+        parse_submission_response(&check_status(day, response)?)
+    }
+}
+
+/// Escape a value for an `application/x-www-form-urlencoded` body.
+fn form_encode(value: &str) -> String {
+    let mut encoded = String::new();
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            b' ' => encoded.push('+'),
+            byte => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+fn check_status(day: u32, response: HttpResponse) -> Result<String, AocError> {
+    match response.status {
+        200 => Ok(response.body),
+        404 => Err(AocError::Http {
+            status: 404,
+            message: format!("Day {} is not unlocked yet", day),
+        }),
+        400 => Err(AocError::Http {
+            status: 400,
+            message: "The session token was rejected. Log in again and update it".to_string(),
+        }),
+        status => Err(AocError::Http {
+            status,
+            message: format!("Unexpected response: {}", response.body.trim()),
+        }),
+    }
+}
+
+/// Read the verdict out of the page the website answers a submission with.
+fn parse_submission_response(body: &str) -> Result<Verdict, AocError> {
+    let refused = |message: &str| AocError::Http {
+        status: 200,
+        message: message.to_string(),
+    };
+
+    if body.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if body.contains("your answer is too high") {
+        Ok(Verdict::TooHigh)
+    } else if body.contains("your answer is too low") {
+        Ok(Verdict::TooLow)
+    } else if body.contains("That's not the right answer") {
+        Ok(Verdict::Wrong)
+    } else if body.contains("You gave an answer too recently") {
+        Err(refused(
+            "An answer was given too recently. Wait before trying again",
+        ))
+    } else if body.contains("You don't seem to be solving the right level") {
+        Err(refused(
+            "Not the right level. The part may already be solved, or still locked",
+        ))
+    } else {
+        Err(refused("Could not find a verdict in the response"))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SubmitOutcome {
+    /// The ledger already knew the verdict, so nothing was sent.
+    Known(Verdict),
+    Submitted(Verdict),
+}
+
+/// Submit an answer unless the ledger already knows how it fares, and record the verdict.
+pub fn submit_to_ledger(
+    remote: &Remote,
+    ledger: &mut Ledger,
+    day: u32,
+    part: Part,
+    answer: &str,
+) -> Result<SubmitOutcome, AocError> {
+    if let Some(verdict) = ledger.known_verdict(day, part, answer) {
+        return Ok(SubmitOutcome::Known(verdict));
+    }
+    let verdict = remote.submit_answer(day, part, answer)?;
+    ledger.add(day, part, answer, verdict);
+    Ok(SubmitOutcome::Submitted(verdict))
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FetchOutcome {
+    AlreadyCached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Download the input for a day into the cache, unless it is already there.
+pub fn fetch_to_cache(
+    remote: &Remote,
+    cache: &InputCache,
+    day: u32,
+) -> Result<FetchOutcome, AocError> {
+    if cache.contains(day) {
+        return Ok(FetchOutcome::AlreadyCached(cache.path(day)));
+    }
+    let raw_input = remote.fetch_input(day)?;
+    Ok(FetchOutcome::Downloaded(cache.store(day, &raw_input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::stand_in::StandInServer;
+    use crate::http::TcpHttpClient;
+
+    fn remote_for(server: &StandInServer, cache: &InputCache, session: Option<&str>) -> Remote {
+        let config = Config {
+            session: session.map(str::to_string),
+            base_url: server.base_url.clone(),
+            request_interval: Duration::ZERO,
+            ..Config::default()
+        };
+        let rate_limiter = RateLimiter::new(cache.root().join(".last_request"), Duration::ZERO);
+        Remote::new(Box::new(TcpHttpClient), config, rate_limiter)
+    }
+
+    fn stand_in_website() -> StandInServer {
+        StandInServer::start(|request| {
+            if request.header("Cookie") != Some("session=good") {
+                return (400, "Puzzle inputs differ by user.".to_string());
+            }
+            match request.path.as_str() {
+                "/2024/day/1/input" => (200, "3   4\n4   3\n".to_string()),
+                _ => (404, "Not found".to_string()),
+            }
+        })
+    }
+
+    #[test]
+    fn fetch_stores_input_in_cache() {
+        let server = stand_in_website();
+        let cache = InputCache::new(crate::test_dir("fetch_stores"));
+        let remote = remote_for(&server, &cache, Some("good"));
+
+        let outcome = fetch_to_cache(&remote, &cache, 1).unwrap();
+
+        assert_eq!(outcome, FetchOutcome::Downloaded(cache.path(1)));
+        assert_eq!(cache.read(1).unwrap(), "3   4\n4   3\n");
+        let received = server.received();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].method, "GET");
+        assert!(received[0].header("User-Agent").is_some());
+    }
+
+    #[test]
+    fn fetch_never_refetches_cached_input() {
+        let server = stand_in_website();
+        let cache = InputCache::new(crate::test_dir("fetch_never_refetches"));
+        let remote = remote_for(&server, &cache, Some("good"));
+
+        fetch_to_cache(&remote, &cache, 1).unwrap();
+        let outcome = fetch_to_cache(&remote, &cache, 1).unwrap();
+
+        assert_eq!(outcome, FetchOutcome::AlreadyCached(cache.path(1)));
+        assert_eq!(server.received().len(), 1);
+    }
+
+    #[test]
+    fn fetch_reports_locked_day() {
+        let server = stand_in_website();
+        let cache = InputCache::new(crate::test_dir("fetch_locked"));
+        let remote = remote_for(&server, &cache, Some("good"));
+
+        let err = fetch_to_cache(&remote, &cache, 25).unwrap_err();
+
+        assert!(matches!(err, AocError::Http { status: 404, .. }));
+        assert!(err.to_string().contains("not unlocked"));
+        assert!(!cache.contains(25));
+    }
+
+    #[test]
+    fn fetch_reports_bad_session() {
+        let server = stand_in_website();
+        let cache = InputCache::new(crate::test_dir("fetch_bad_session"));
+        let remote = remote_for(&server, &cache, Some("expired"));
+
+        let err = fetch_to_cache(&remote, &cache, 1).unwrap_err();
+
+        assert!(matches!(err, AocError::Http { status: 400, .. }));
+        assert!(!cache.contains(1));
+    }
+
+    #[test]
+    fn fetch_without_session_sends_nothing() {
+        let server = stand_in_website();
+        let cache = InputCache::new(crate::test_dir("fetch_no_session"));
+        let remote = remote_for(&server, &cache, None);
+
+        let err = fetch_to_cache(&remote, &cache, 1).unwrap_err();
+
+        assert!(matches!(err, AocError::Config(_)));
+        assert!(server.received().is_empty());
+    }
+
+    fn stand_in_answer_checker() -> StandInServer {
+        StandInServer::start(|request| {
+            let page = match request.body.as_str() {
+                "level=1&answer=41" => "<article><p>That's the right answer!</p></article>",
+                "level=1&answer=50" => {
+                    "<article><p>That's not the right answer; your answer is too high.</p></article>"
+                }
+                _ => "<article><p>That's not the right answer.</p></article>",
+            };
+            (200, page.to_string())
+        })
+    }
+
+    #[test]
+    fn submit_records_verdicts() {
+        let server = stand_in_answer_checker();
+        let cache = InputCache::new(crate::test_dir("submit_records"));
+        let remote = remote_for(&server, &cache, Some("good"));
+        let mut ledger = Ledger::default();
+
+        let too_high = submit_to_ledger(&remote, &mut ledger, 6, Part::One, "50").unwrap();
+        let correct = submit_to_ledger(&remote, &mut ledger, 6, Part::One, "41").unwrap();
+
+        assert_eq!(too_high, SubmitOutcome::Submitted(Verdict::TooHigh));
+        assert_eq!(correct, SubmitOutcome::Submitted(Verdict::Correct));
+        assert_eq!(ledger.correct_answer(6, Part::One), Some("41"));
+        let received = server.received();
+        assert_eq!(received[0].method, "POST");
+        assert_eq!(received[0].path, "/2024/day/6/answer");
+    }
+
+    #[test]
+    fn submit_never_resends_known_wrong_guess() {
+        let server = stand_in_answer_checker();
+        let cache = InputCache::new(crate::test_dir("submit_known"));
+        let remote = remote_for(&server, &cache, Some("good"));
+        let mut ledger = Ledger::default();
+        ledger.add(6, Part::One, "50", Verdict::TooHigh);
+
+        let outcome = submit_to_ledger(&remote, &mut ledger, 6, Part::One, "60").unwrap();
+
+        assert_eq!(outcome, SubmitOutcome::Known(Verdict::TooHigh));
+        assert!(server.received().is_empty());
+    }
+
+    #[test]
+    fn submit_encodes_the_answer() {
+        let server = stand_in_answer_checker();
+        let cache = InputCache::new(crate::test_dir("submit_encodes"));
+        let remote = remote_for(&server, &cache, Some("good"));
+
+        remote
+            .submit_answer(17, Part::Two, "4,6 3&level=1")
+            .unwrap();
+
+        assert_eq!(
+            server.received()[0].body,
+            "level=2&answer=4%2C6+3%26level%3D1"
+        );
+    }
+
+    #[test]
+    fn submission_response_without_verdict_is_an_error() {
+        let body = "<article><p>You gave an answer too recently.</p></article>";
+
+        assert!(parse_submission_response(body).is_err());
+    }
+
+    #[test]
+    fn rate_limiter_spaces_out_requests() {
+        let stamp_path = crate::test_dir("rate_limiter").join(".last_request");
+        let interval = Duration::from_millis(100);
+        let rate_limiter = RateLimiter::new(&stamp_path, interval);
+
+        let start = std::time::Instant::now();
+        rate_limiter.wait().unwrap();
+        rate_limiter.wait().unwrap();
+
+        // The stamp only has millisecond resolution
+        assert!(start.elapsed() >= interval - Duration::from_millis(2));
+    }
+}
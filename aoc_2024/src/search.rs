@@ -0,0 +1,291 @@
+//! Shortest path searches over any graph given as a closure from a state to its neighbors, and
+//! wrappers for searching the cells of an `Array2D`.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::array2d::Boundary;
+use crate::vec2::Vec2;
+use crate::Array2D;
+
+/// What a search found: the cost of the cheapest way to each state it settled, and the goal if
+/// it reached one.
+#[derive(Clone, Debug)]
+pub struct Searched<S> {
+    pub costs: HashMap<S, u64>,
+    pub goal: Option<S>,
+    /// The state before each state on its cheapest path. The start has none.
+    parents: HashMap<S, S>,
+}
+
+impl<S: Clone + Eq + Hash> Searched<S> {
+    fn new() -> Searched<S> {
+        Searched {
+            costs: HashMap::new(),
+            goal: None,
+            parents: HashMap::new(),
+        }
+    }
+
+    pub fn cost(&self, state: &S) -> Option<u64> {
+        self.costs.get(state).copied()
+    }
+
+    /// The cheapest path from the start to the state, including both.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        self.costs.get(state)?;
+        let mut path = vec![state.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.path(self.goal.as_ref()?)
+    }
+}
+
+/// Breadth first search where every step costs 1. Stops at the first state where `is_goal` is
+/// true, or when everything reachable has been seen if it never is.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Searched<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut searched = Searched::new();
+    searched.costs.insert(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            searched.goal = Some(state);
+            break;
+        }
+        let cost = searched.costs[&state] + 1;
+        for next in neighbors(&state) {
+            if !searched.costs.contains_key(&next) {
+                searched.costs.insert(next.clone(), cost);
+                searched.parents.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    searched
+}
+
+/// Cheapest paths where `neighbors` gives each next state with the cost of stepping to it.
+pub fn dijkstra<S, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Searched<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(start, neighbors, |_| 0, is_goal)
+}
+
+/// Like `dijkstra`, but trying the states that `heuristic` estimates to be closest to the goal
+/// first. The estimate must never be more than the real remaining cost, and must not drop by
+/// more than the cost of a step, or the paths found may not be the cheapest.
+pub fn astar<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Searched<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut searched = Searched::new();
+    // The cheapest known cost of the states that are not settled yet, and where they came from
+    let mut tentative: HashMap<S, (u64, Option<S>)> = HashMap::new();
+    // The heap holds indices into `states` so that states do not need to be ordered
+    let mut queue = BinaryHeap::new();
+    let mut states = Vec::new();
+
+    queue.push(Reverse((heuristic(&start), 0, states.len())));
+    tentative.insert(start.clone(), (0, None));
+    states.push(start);
+
+    while let Some(Reverse((_, cost, idx))) = queue.pop() {
+        let state = states[idx].clone();
+        match tentative.get(&state) {
+            // Settled already, or queued again later with a lower cost
+            None => continue,
+            Some(&(best, _)) if best < cost => continue,
+            Some(_) => {}
+        }
+        let (_, parent) = tentative.remove(&state).unwrap();
+        searched.costs.insert(state.clone(), cost);
+        if let Some(parent) = parent {
+            searched.parents.insert(state.clone(), parent);
+        }
+        if is_goal(&state) {
+            searched.goal = Some(state);
+            break;
+        }
+
+        for (next, step) in neighbors(&state) {
+            if searched.costs.contains_key(&next) {
+                continue;
+            }
+            let next_cost = cost + step;
+            if tentative
+                .get(&next)
+                .is_none_or(|&(best, _)| next_cost < best)
+            {
+                queue.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    states.len(),
+                )));
+                tentative.insert(next.clone(), (next_cost, Some(state.clone())));
+                states.push(next);
+            }
+        }
+    }
+    searched
+}
+
+impl<T> Array2D<T> {
+    /// The passable cells above, right of, below and left of `pos`.
+    fn passable_neighbors<'a>(
+        &'a self,
+        pos: Vec2,
+        passable: &'a impl Fn(&T) -> bool,
+    ) -> impl Iterator<Item = Vec2> + 'a {
+        self.neighbors4(pos)
+            .filter(move |&next| passable(&self[next]))
+    }
+
+    /// The number of steps along rows and columns from `start` to each cell, moving only onto
+    /// cells that are `passable`. Cells that cannot be reached are `None`, which is all of them
+    /// if `start` is outside the grid.
+    pub fn distances(&self, start: Vec2, passable: impl Fn(&T) -> bool) -> Array2D<Option<u64>> {
+        if !self.in_bounds(start) {
+            return Array2D::new(self.height, self.width, None);
+        }
+        let searched = bfs(
+            start,
+            |&pos| self.passable_neighbors(pos, &passable),
+            |_| false,
+        );
+        Array2D::from_fn(self.height, self.width, |row, col| {
+            searched.cost(&Vec2::from_index(row, col))
+        })
+    }
+
+    /// A shortest path along rows and columns from `start` to `goal`, moving only onto cells
+    /// that are `passable`. There is none if `start` is outside the grid.
+    pub fn shortest_path(
+        &self,
+        start: Vec2,
+        goal: Vec2,
+        passable: impl Fn(&T) -> bool,
+    ) -> Option<Vec<Vec2>> {
+        if !self.in_bounds(start) {
+            return None;
+        }
+        // Going around the edge can be shorter than the distance across the grid
+        let wraps = matches!(self.boundary(), Boundary::Wrap);
+        let searched = astar(
+            start,
+            |&pos| {
+                self.passable_neighbors(pos, &passable)
+                    .map(|next| (next, 1))
+            },
+            |&pos| if wraps { 0 } else { pos.manhattan(goal) as u64 },
+            |&pos| pos == goal,
+        );
+        searched.goal_path()
+    }
+}
+
+/// The distances as a grid of digits that can be printed, from 0 for the closest cells to 9
+/// for the farthest, with `#` for cells that cannot be reached.
+pub fn heatmap(distances: &Array2D<Option<u64>>) -> Array2D<char> {
+    let max = distances
+        .iter()
+        .filter_map(|(_, &distance)| distance)
+        .max()
+        .unwrap_or(0);
+    Array2D::from_fn(
+        distances.height,
+        distances.width,
+        |row, col| match distances[Vec2::from_index(row, col)] {
+            Some(distance) if max > 0 => char::from(b'0' + (distance * 9 / max) as u8),
+            Some(_) => '0',
+            None => '#',
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bfs_counts_steps() {
+        // From 1, either add one or double
+        let searched = bfs(1u64, |&n| [n + 1, n * 2], |&n| n == 10);
+
+        assert_eq!(searched.goal, Some(10));
+        assert_eq!(searched.cost(&10), Some(4));
+        assert_eq!(searched.goal_path(), Some(vec![1, 2, 4, 5, 10]));
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheap_detours() {
+        let edges: HashMap<char, Vec<(char, u64)>> = HashMap::from([
+            ('a', vec![('b', 1), ('d', 10)]),
+            ('b', vec![('c', 2)]),
+            ('c', vec![('d', 3)]),
+            ('d', vec![]),
+        ]);
+        let searched = dijkstra('a', |state| edges[state].clone(), |_| false);
+
+        assert_eq!(searched.cost(&'d'), Some(6));
+        assert_eq!(searched.path(&'d'), Some(vec!['a', 'b', 'c', 'd']));
+        assert_eq!(searched.goal, None);
+    }
+
+    #[test]
+    fn grid_searches_go_around_walls() {
+        let array: Array2D<char> = "..#.\n..#.\n....\n".parse().unwrap();
+        let open = |&ch: &char| ch == '.';
+
+        let path = array
+            .shortest_path(Vec2::new(0, 0), Vec2::new(3, 0), open)
+            .unwrap();
+        assert_eq!(path.len(), 8);
+        assert_eq!(path.first(), Some(&Vec2::new(0, 0)));
+        assert_eq!(path.last(), Some(&Vec2::new(3, 0)));
+
+        let distances = array.distances(Vec2::new(0, 0), open);
+        assert_eq!(distances[Vec2::new(3, 0)], Some(7));
+        assert_eq!(heatmap(&distances).to_string(), "01#9\n12#7\n2356\n");
+    }
+    #[test]
+    fn shortest_path_needs_a_start_inside_the_grid() {
+        let array: Array2D<char> = "ab\ncd\n".parse().unwrap();
+        let path = array.shortest_path(Vec2::new(-1, 0), Vec2::new(1, 1), |_| true);
+        assert_eq!(path, None);
+    }
+
+    #[test]
+    fn distances_need_a_start_inside_the_grid() {
+        let array: Array2D<char> = "ab\ncd\n".parse().unwrap();
+        let distances = array.distances(Vec2::new(-1, 0), |_| true);
+        assert!(distances.iter().all(|(_, distance)| distance.is_none()));
+    }
+}
@@ -0,0 +1,204 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use crate::cli::{self, Options};
+use crate::diagnostics;
+use crate::error::AocError;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    fn half(&self) -> &'static str {
+        match self {
+            Part::One => "first",
+            Part::Two => "second",
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Answer type for a part that has not been solved yet. It has no values, so it can never be
+/// returned.
+pub enum Unsolved {}
+
+impl Display for Unsolved {
+    fn fmt(&self, _: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {}
+    }
+}
+
+/// Implemented once per day. The input is parsed once and then shared by both parts.
+pub trait Solution {
+    const DAY: u32;
+    /// The example inputs from the puzzle text.
+    const EXAMPLES: &'static [&'static str] = &[];
+
+    type Input;
+    type AnswerOne: Display;
+    type AnswerTwo: Display;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, AocError>;
+
+    fn part_one(input: &Self::Input) -> Result<Self::AnswerOne, AocError>;
+
+    fn part_two(_input: &Self::Input) -> Result<Option<Self::AnswerTwo>, AocError> {
+        Ok(None)
+    }
+}
+
+pub struct PartAnswer {
+    pub part: Part,
+    pub answer: Option<String>,
+    /// Wall time spent solving the part, not counting parsing.
+    pub duration: Duration,
+    /// Diagnostics emitted while solving the part.
+    pub diagnostics: Vec<String>,
+}
+
+impl Display for PartAnswer {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.answer {
+            Some(answer) => write!(
+                f,
+                "The answer to the {} half is: {}",
+                self.part.half(),
+                answer
+            ),
+            None => write!(f, "The {} half is not solved yet.", self.part.half()),
+        }
+    }
+}
+
+/// The answers to the requested parts of a day, with how long each step took.
+pub struct Solved {
+    pub parse_duration: Duration,
+    /// Diagnostics emitted while parsing the input.
+    pub parse_diagnostics: Vec<String>,
+    pub answers: Vec<PartAnswer>,
+}
+
+/// Parse the input once and solve the requested parts. Diagnostics are collected, not printed.
+pub fn solve<S: Solution>(raw_input: &str, parts: &[Part]) -> Result<Solved, AocError> {
+    let start = Instant::now();
+    let (input, parse_diagnostics) = diagnostics::capture(|| S::parse(raw_input));
+    let input = input?;
+    let parse_duration = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let (answer, diagnostics) = diagnostics::capture(|| match part {
+                Part::One => S::part_one(&input).map(|answer| Some(answer.to_string())),
+                Part::Two => {
+                    S::part_two(&input).map(|answer| answer.map(|answer| answer.to_string()))
+                }
+            });
+            Ok(PartAnswer {
+                part,
+                answer: answer?,
+                duration: start.elapsed(),
+                diagnostics,
+            })
+        })
+        .collect::<Result<_, AocError>>()?;
+
+    Ok(Solved {
+        parse_duration,
+        parse_diagnostics,
+        answers,
+    })
+}
+
+/// Solve one part of an embedded example and check the answer. Used by `example_tests!`.
+pub fn assert_example<S: Solution>(example: usize, part: Part, expected: &str) {
+    let raw_input = S::EXAMPLES
+        .get(example - 1)
+        .unwrap_or_else(|| panic!("Day {} has no example {}", S::DAY, example));
+
+    let solved = solve::<S>(raw_input, &[part])
+        .unwrap_or_else(|err| panic!("Day {} example {} failed: {}", S::DAY, example, err));
+
+    assert_eq!(
+        solved.answers[0].answer.as_deref(),
+        Some(expected),
+        "Day {} example {} part {}",
+        S::DAY,
+        example,
+        part
+    );
+}
+
+/// Generate a test per `name: (example, part, expected answer)`, where `example` is the 1-based
+/// index into the solution's `EXAMPLES`.
+///
+/// ```ignore
+/// example_tests! {
+///     Day1;
+///     part_one_example: (1, One, 11),
+///     part_two_example: (1, Two, 31),
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty; $($name:ident: ($example:expr, $part:ident, $expected:expr)),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                $crate::solution::assert_example::<$solution>(
+                    $example,
+                    $crate::solution::Part::$part,
+                    &$expected.to_string(),
+                );
+            }
+        )*
+    };
+}
+
+/// Type-erased entry in the table of registered days.
+pub struct Day {
+    pub number: u32,
+    pub examples: &'static [&'static str],
+    pub solve: fn(&str, &[Part]) -> Result<Solved, AocError>,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Day {
+        Day {
+            number: S::DAY,
+            examples: S::EXAMPLES,
+            solve: solve::<S>,
+        }
+    }
+}
+
+/// Entry point shared by the single-day binaries.
+pub fn run_binary<S: Solution>() {
+    let day = Day::of::<S>();
+    let usage = format!("Usage: day_{} [options] [<input>]", day.number);
+
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => cli::exit_with(err, &usage),
+    };
+    let source = options.source();
+
+    if let Err(err) = cli::run(&day, &source, &options) {
+        eprintln!("{}: {}", source, err);
+        std::process::exit(cli::EXIT_FAILURE);
+    }
+}
@@ -0,0 +1,194 @@
+//! A grid without fixed bounds, for puzzles where the world grows or only a few cells are used.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::direction::Direction;
+use crate::vec2::Vec2;
+use crate::Array2D;
+
+/// A grid that only stores the cells that have been set. Positions can be anywhere, including
+/// negative rows and columns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Vec2, T>,
+    /// The top left and bottom right corners of the cells, if there are any.
+    bounds: Option<(Vec2, Vec2)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle holding every cell that
+    /// is set, or `None` if the grid is empty.
+    pub fn bounds(&self) -> Option<(Vec2, Vec2)> {
+        self.bounds
+    }
+
+    pub fn contains(&self, pos: Vec2) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: Vec2) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Vec2) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    /// Set the value at the position, returning the value that was there before.
+    pub fn set(&mut self, pos: Vec2, value: T) -> Option<T> {
+        let corners = self.bounds.into_iter().flat_map(|(min, max)| [min, max]);
+        self.bounds = Self::bounds_of(corners.chain([pos]));
+        self.cells.insert(pos, value)
+    }
+
+    /// Clear the value at the position, returning it if there was one.
+    pub fn remove(&mut self, pos: Vec2) -> Option<T> {
+        let value = self.cells.remove(&pos)?;
+        if let Some((min, max)) = self.bounds {
+            if pos.x == min.x || pos.x == max.x || pos.y == min.y || pos.y == max.y {
+                // The removed cell may have been the only one on that edge
+                self.bounds = Self::bounds_of(self.cells.keys().copied());
+            }
+        }
+        Some(value)
+    }
+
+    fn bounds_of(positions: impl Iterator<Item = Vec2>) -> Option<(Vec2, Vec2)> {
+        positions.fold(None, |bounds, pos| match bounds {
+            Some((min, max)) => Some((
+                Vec2::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Vec2::new(max.x.max(pos.x), max.y.max(pos.y)),
+            )),
+            None => Some((pos, pos)),
+        })
+    }
+
+    /// The positions above, right of, below and left of `pos`. Unlike on an `Array2D`, these
+    /// are never outside the grid.
+    pub fn neighbors4(&self, pos: Vec2) -> impl Iterator<Item = Vec2> {
+        Direction::CARDINAL
+            .into_iter()
+            .map(move |dir| dir.step(pos))
+    }
+
+    /// Like `neighbors4`, but including the diagonal neighbors.
+    pub fn neighbors8(&self, pos: Vec2) -> impl Iterator<Item = Vec2> {
+        Direction::ALL.into_iter().map(move |dir| dir.step(pos))
+    }
+
+    /// Every cell that is set with its position, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// The cells inside the bounds as an `Array2D`, with `empty` where no value is set. The top
+    /// left corner of the bounds becomes row 0, column 0.
+    pub fn to_array(&self, empty: T) -> Array2D<T> {
+        let Some((min, max)) = self.bounds else {
+            return Array2D::new(0, 0, empty);
+        };
+        let height = (max.y - min.y + 1) as usize;
+        let width = (max.x - min.x + 1) as usize;
+        Array2D::from_fn(height, width, |row, col| {
+            let pos = min + Vec2::from_index(row, col);
+            self.cells.get(&pos).unwrap_or(&empty).clone()
+        })
+    }
+}
+
+impl<T> FromIterator<(Vec2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Vec2, T)>>(iter: I) -> SparseGrid<T> {
+        let cells: HashMap<Vec2, T> = iter.into_iter().collect();
+        let bounds = SparseGrid::<T>::bounds_of(cells.keys().copied());
+        SparseGrid { cells, bounds }
+    }
+}
+
+/// Every cell of the array, at the same position. Use `filter` on the array's `into_iter`
+/// first to leave out the cells that should be empty.
+impl<T> From<Array2D<T>> for SparseGrid<T> {
+    fn from(array: Array2D<T>) -> SparseGrid<T> {
+        array.into_iter().collect()
+    }
+}
+
+/// The cells inside the bounds row by row, with `.` where no value is set.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+        for row in min.y..=max.y {
+            for col in min.x..=max.x {
+                match self.get(Vec2::new(col, row)) {
+                    Some(value) => write!(f, "{}", value)?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_follow_the_cells() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.set(Vec2::new(3, 2), '#');
+        grid.set(Vec2::new(0, -1), '#');
+        grid.set(Vec2::new(5, 0), 'x');
+        assert_eq!(grid.bounds(), Some((Vec2::new(0, -1), Vec2::new(5, 2))));
+        assert_eq!(grid.to_string(), "#.....\n.....x\n......\n...#..\n");
+
+        assert_eq!(grid.remove(Vec2::new(0, -1)), Some('#'));
+        assert_eq!(grid.remove(Vec2::new(0, -1)), None);
+        assert!(grid.contains(Vec2::new(3, 2)));
+        assert_eq!(grid.bounds(), Some((Vec2::new(3, 0), Vec2::new(5, 2))));
+        assert_eq!(grid.neighbors8(Vec2::new(-7, -7)).count(), 8);
+    }
+
+    #[test]
+    fn converts_to_and_from_array() {
+        let array: Array2D<char> = "#..\n..#\n".parse().unwrap();
+        let grid: SparseGrid<char> = array
+            .clone()
+            .into_iter()
+            .filter(|&(_, value)| value == '#')
+            .collect();
+
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.get(Vec2::new(2, 1)), Some(&'#'));
+        assert_eq!(grid.to_array('.'), array);
+        assert_eq!(SparseGrid::from(array.clone()).to_array(' '), array);
+    }
+}
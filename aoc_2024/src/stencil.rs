@@ -0,0 +1,270 @@
+//! Matching small 2D templates against an `Array2D`, for word searches and similar puzzles.
+
+use crate::vec2::Vec2;
+use crate::Array2D;
+
+/// How a stencil is laid onto the grid: first mirrored left to right if `flipped`, then turned
+/// clockwise by `turns` quarter turns.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Orientation {
+    pub turns: u8,
+    pub flipped: bool,
+}
+
+impl Orientation {
+    pub const IDENTITY: Orientation = Orientation {
+        turns: 0,
+        flipped: false,
+    };
+
+    /// The four rotations.
+    pub const ROTATIONS: [Orientation; 4] = [
+        Orientation::IDENTITY,
+        Orientation {
+            turns: 1,
+            flipped: false,
+        },
+        Orientation {
+            turns: 2,
+            flipped: false,
+        },
+        Orientation {
+            turns: 3,
+            flipped: false,
+        },
+    ];
+
+    /// The four rotations, and the four rotations of the mirror image.
+    pub const ALL: [Orientation; 8] = [
+        Orientation::ROTATIONS[0],
+        Orientation::ROTATIONS[1],
+        Orientation::ROTATIONS[2],
+        Orientation::ROTATIONS[3],
+        Orientation {
+            turns: 0,
+            flipped: true,
+        },
+        Orientation {
+            turns: 1,
+            flipped: true,
+        },
+        Orientation {
+            turns: 2,
+            flipped: true,
+        },
+        Orientation {
+            turns: 3,
+            flipped: true,
+        },
+    ];
+}
+
+/// A rectangular template. Cells that are `None` match any value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stencil<T> {
+    pub height: usize,
+    pub width: usize,
+    cells: Vec<Option<T>>,
+}
+
+impl<T: Clone + PartialEq> Stencil<T> {
+    /// A stencil from its cells, row by row. Panics if there are not `height * width` of them.
+    pub fn new(height: usize, width: usize, cells: Vec<Option<T>>) -> Stencil<T> {
+        assert_eq!(
+            cells.len(),
+            height * width,
+            "Stencil cells do not fit its shape"
+        );
+        Stencil {
+            height,
+            width,
+            cells,
+        }
+    }
+
+    /// A stencil from lines of text, where `wildcard` matches anything and shorter lines are
+    /// padded with wildcards.
+    pub fn from_rows(rows: &[&str], wildcard: char, value: impl Fn(char) -> T) -> Stencil<T> {
+        let width = rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut cells = Vec::with_capacity(rows.len() * width);
+        for row in rows {
+            let mut chars = row.chars();
+            for _ in 0..width {
+                cells.push(chars.next().filter(|&ch| ch != wildcard).map(&value));
+            }
+        }
+        Stencil::new(rows.len(), width, cells)
+    }
+
+    /// The cell at the position, or `None` for a wildcard. Panics if the position is outside
+    /// the stencil.
+    pub fn get(&self, pos: Vec2) -> Option<&T> {
+        assert!(
+            (0..self.width as i64).contains(&pos.x) && (0..self.height as i64).contains(&pos.y),
+            "Position {:?} is outside the stencil",
+            pos
+        );
+        self.cells[pos.y as usize * self.width + pos.x as usize].as_ref()
+    }
+
+    /// The stencil as it is laid onto the grid in the orientation.
+    pub fn oriented(&self, orientation: Orientation) -> Stencil<T> {
+        let mut stencil = self.clone();
+        if orientation.flipped {
+            for row in 0..self.height {
+                stencil.cells[row * self.width..(row + 1) * self.width].reverse();
+            }
+        }
+        for _ in 0..orientation.turns % 4 {
+            let (height, width) = (stencil.height, stencil.width);
+            // Row r becomes column height - 1 - r
+            let cells = (0..width)
+                .flat_map(|row| (0..height).map(move |col| (height - 1 - col, row)))
+                .map(|(row, col)| stencil.get(Vec2::from_index(row, col)).cloned())
+                .collect();
+            stencil = Stencil::new(width, height, cells);
+        }
+        stencil
+    }
+
+    /// Whether the stencil matches with its top left corner at the position. The whole stencil
+    /// must lie inside the grid, wildcards too, whatever the grid's boundary.
+    pub fn matches_at(&self, array: &Array2D<T>, top_left: Vec2) -> bool {
+        (0..self.height).all(|row| {
+            (0..self.width).all(|col| {
+                let offset = Vec2::from_index(row, col);
+                let pos = top_left + offset;
+                array.in_bounds(pos)
+                    && self
+                        .get(offset)
+                        .is_none_or(|expected| array[pos] == *expected)
+            })
+        })
+    }
+}
+
+/// Where a stencil matched, as the top left corner of the oriented stencil.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StencilMatch {
+    pub pos: Vec2,
+    pub orientation: Orientation,
+}
+
+impl<T: Clone + PartialEq> Array2D<T> {
+    /// All the places where the stencil matches in one of the orientations. Orientations that
+    /// lay the stencil out the same as an earlier one are skipped, so a symmetric stencil is not
+    /// found twice at the same place.
+    pub fn find_stencil(
+        &self,
+        stencil: &Stencil<T>,
+        orientations: &[Orientation],
+    ) -> Vec<StencilMatch> {
+        let mut oriented: Vec<(Orientation, Stencil<T>)> = Vec::new();
+        for &orientation in orientations {
+            let candidate = stencil.oriented(orientation);
+            if oriented.iter().all(|(_, other)| *other != candidate) {
+                oriented.push((orientation, candidate));
+            }
+        }
+
+        let mut matches = Vec::new();
+        for (pos, _) in self.iter() {
+            for (orientation, stencil) in &oriented {
+                if stencil.matches_at(self, pos) {
+                    matches.push(StencilMatch {
+                        pos,
+                        orientation: *orientation,
+                    });
+                }
+            }
+        }
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array2d::Boundary;
+
+    fn char_stencil(rows: &[&str]) -> Stencil<char> {
+        Stencil::from_rows(rows, '.', |ch| ch)
+    }
+
+    fn char_array(rows: &[&str]) -> Array2D<char> {
+        let mut array = Array2D::new(rows.len(), rows[0].len(), ' ');
+        for (row, line) in rows.iter().enumerate() {
+            for (col, ch) in line.chars().enumerate() {
+                array.set(Vec2::from_index(row, col), ch).unwrap();
+            }
+        }
+        array
+    }
+
+    #[test]
+    fn oriented_turns_and_flips() {
+        let stencil = char_stencil(&["AB", "C."]);
+
+        assert_eq!(
+            stencil.oriented(Orientation::ROTATIONS[1]),
+            char_stencil(&["CA", ".B"])
+        );
+        assert_eq!(
+            stencil.oriented(Orientation::ALL[4]),
+            char_stencil(&["BA", ".C"])
+        );
+        assert_eq!(
+            char_stencil(&["XMAS"]).oriented(Orientation::ROTATIONS[3]),
+            char_stencil(&["S", "A", "M", "X"])
+        );
+    }
+
+    #[test]
+    fn find_stencil_reports_positions_and_orientations() {
+        let array = char_array(&["XMAS", "A..M", "SAMX"]);
+        let matches = array.find_stencil(&char_stencil(&["XMAS"]), &Orientation::ALL);
+
+        assert_eq!(
+            matches,
+            vec![
+                StencilMatch {
+                    pos: Vec2::ZERO,
+                    orientation: Orientation::IDENTITY
+                },
+                StencilMatch {
+                    pos: Vec2::new(0, 2),
+                    orientation: Orientation::ROTATIONS[2]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn find_stencil_skips_wildcards() {
+        let array = char_array(&["M.S.S.S", ".A...A.", "M.S.M.M"]);
+        let stencil = char_stencil(&["M.S", ".A.", "M.S"]);
+
+        let matches = array.find_stencil(&stencil, &Orientation::ALL);
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].pos, Vec2::ZERO);
+        assert_eq!(matches[1].pos, Vec2::new(4, 0));
+        assert_eq!(matches[1].orientation.turns, 3);
+    }
+
+    #[test]
+    fn find_stencil_ignores_the_boundary() {
+        let stencil = char_stencil(&["XMAS"]);
+        let wrapping = char_array(&["ASXM"]).with_boundary(Boundary::Wrap);
+        assert!(wrapping
+            .find_stencil(&stencil, &Orientation::ALL)
+            .is_empty());
+
+        let padded = char_array(&["XMA"]).with_boundary(Boundary::Default('S'));
+        assert!(padded.find_stencil(&stencil, &Orientation::ALL).is_empty());
+        assert!(!char_stencil(&["X.."]).matches_at(&padded, Vec2::new(1, 0)));
+    }
+}
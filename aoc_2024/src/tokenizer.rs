@@ -0,0 +1,202 @@
+//! Tokenizer for instructions hidden in corrupted memory, like `mul(2,4)` in
+//! `xmul(2,4)%&mul[3,7]`. Puzzles declare their instructions as templates, and everything that
+//! does not match one exactly is skipped as corruption.
+
+/// An instruction `name(a,b,...)` with a fixed number of unsigned decimal arguments.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Template<K> {
+    /// What tokens matching the template are reported as.
+    pub kind: K,
+    pub name: &'static str,
+    pub args: usize,
+    pub min_digits: usize,
+    pub max_digits: usize,
+}
+
+impl<K: Copy> Template<K> {
+    /// A template whose arguments have 1 to 3 digits.
+    pub const fn new(kind: K, name: &'static str, args: usize) -> Template<K> {
+        Template {
+            kind,
+            name,
+            args,
+            min_digits: 1,
+            max_digits: 3,
+        }
+    }
+
+    pub const fn digits(self, min_digits: usize, max_digits: usize) -> Template<K> {
+        Template {
+            min_digits,
+            max_digits,
+            ..self
+        }
+    }
+
+    /// The length of the instruction at the start of `bytes` and its arguments, if there is one.
+    fn match_at(&self, bytes: &[u8]) -> Option<(usize, Vec<u64>)> {
+        let mut idx = self.name.len();
+        if bytes.get(..idx)? != self.name.as_bytes() || bytes.get(idx) != Some(&b'(') {
+            return None;
+        }
+        idx += 1;
+
+        let mut args = Vec::with_capacity(self.args);
+        for iarg in 0..self.args {
+            if iarg > 0 {
+                if bytes.get(idx) != Some(&b',') {
+                    return None;
+                }
+                idx += 1;
+            }
+            let num_digits = bytes[idx..]
+                .iter()
+                .take_while(|byte| byte.is_ascii_digit())
+                .count();
+            if num_digits < self.min_digits || num_digits > self.max_digits {
+                return None;
+            }
+            // Only digits, so this can only fail by overflowing
+            let digits = std::str::from_utf8(&bytes[idx..idx + num_digits]).ok()?;
+            args.push(digits.parse().ok()?);
+            idx += num_digits;
+        }
+
+        if bytes.get(idx) != Some(&b')') {
+            return None;
+        }
+        Some((idx + 1, args))
+    }
+}
+
+/// An instruction found in the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Token<K> {
+    pub kind: K,
+    pub args: Vec<u64>,
+    /// Byte offset of the first byte of the instruction in the input.
+    pub offset: usize,
+    /// Length of the instruction in bytes.
+    pub len: usize,
+}
+
+/// Iterator over the instructions in an input, see `tokenize`.
+pub struct Tokens<'a, K> {
+    templates: &'a [Template<K>],
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<K: Copy> Iterator for Tokens<'_, K> {
+    type Item = Token<K>;
+
+    fn next(&mut self) -> Option<Token<K>> {
+        while self.offset < self.bytes.len() {
+            let rest = &self.bytes[self.offset..];
+            let found = self.templates.iter().find_map(|template| {
+                template
+                    .match_at(rest)
+                    .map(|(len, args)| (template.kind, len, args))
+            });
+
+            match found {
+                Some((kind, len, args)) => {
+                    let token = Token {
+                        kind,
+                        args,
+                        offset: self.offset,
+                        len,
+                    };
+                    self.offset += len;
+                    return Some(token);
+                }
+                None => self.offset += 1,
+            }
+        }
+        None
+    }
+}
+
+/// The instructions in `input` that match one of the templates, in the order they appear. If
+/// several templates match at the same offset, the first one wins.
+pub fn tokenize<'a, K: Copy>(templates: &'a [Template<K>], input: &'a str) -> Tokens<'a, K> {
+    Tokens {
+        templates,
+        bytes: input.as_bytes(),
+        offset: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    enum Kind {
+        Mul,
+        Do,
+        Dont,
+    }
+
+    const TEMPLATES: [Template<Kind>; 3] = [
+        Template::new(Kind::Mul, "mul", 2),
+        Template::new(Kind::Do, "do", 0),
+        Template::new(Kind::Dont, "don't", 0),
+    ];
+
+    #[test]
+    fn tokenize_finds_instructions_with_offsets() {
+        let tokens: Vec<Token<Kind>> =
+            tokenize(&TEMPLATES, "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+undo()").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token {
+                    kind: Kind::Mul,
+                    args: vec![2, 4],
+                    offset: 1,
+                    len: 8
+                },
+                Token {
+                    kind: Kind::Dont,
+                    args: vec![],
+                    offset: 20,
+                    len: 7
+                },
+                Token {
+                    kind: Kind::Mul,
+                    args: vec![5, 5],
+                    offset: 28,
+                    len: 8
+                },
+                Token {
+                    kind: Kind::Do,
+                    args: vec![],
+                    offset: 39,
+                    len: 4
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenize_skips_near_misses() {
+        let input = "mul(1234,5) mul(,5) mul(1,2,3) mul (1,2) mul(1,2 mmul(7,8) do( )";
+        let tokens: Vec<Token<Kind>> = tokenize(&TEMPLATES, input).collect();
+
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].args, vec![7, 8]);
+        assert_eq!(&input[tokens[0].offset..][..tokens[0].len], "mul(7,8)");
+    }
+
+    #[test]
+    fn templates_take_digit_limits() {
+        let templates = [Template::new(Kind::Mul, "mul", 1).digits(2, 4)];
+        let args: Vec<Vec<u64>> = tokenize(&templates, "mul(1)mul(12)mul(1234)mul(12345)")
+            .map(|token| token.args)
+            .collect();
+
+        assert_eq!(args, vec![vec![12], vec![1234]]);
+    }
+}
@@ -0,0 +1,261 @@
+//! Reader and writer for the small subset of TOML used by our config and data files: comments,
+//! `[section]` headers and `key = value` pairs where the value is a string or an integer. Keys
+//! that are not made of letters, digits, `_` and `-` are written as quoted strings.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use crate::error::AocError;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    String(String),
+    Integer(i64),
+}
+
+impl Value {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(value) => Some(value),
+            Value::Integer(_) => None,
+        }
+    }
+
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            Value::String(_) => None,
+            Value::Integer(value) => Some(*value),
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Value::String(value) => {
+                write!(f, "\"")?;
+                for ch in value.chars() {
+                    match ch {
+                        '"' => write!(f, "\\\"")?,
+                        '\\' => write!(f, "\\\\")?,
+                        '\n' => write!(f, "\\n")?,
+                        '\r' => write!(f, "\\r")?,
+                        '\t' => write!(f, "\\t")?,
+                        ch => write!(f, "{}", ch)?,
+                    }
+                }
+                write!(f, "\"")
+            }
+            Value::Integer(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+pub type Table = BTreeMap<String, Value>;
+
+/// Keys before the first section header live in `root`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Document {
+    pub root: Table,
+    pub sections: BTreeMap<String, Table>,
+}
+
+fn is_bare_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-')
+}
+
+fn write_key(f: &mut std::fmt::Formatter, key: &str) -> std::fmt::Result {
+    if is_bare_key(key) {
+        write!(f, "{}", key)
+    } else {
+        write!(f, "{}", Value::String(key.to_string()))
+    }
+}
+
+/// Parse a string whose opening quote has already been stripped from `quoted`. Returns the
+/// string and the text after its closing quote, or an error message.
+fn parse_string(quoted: &str) -> Result<(String, &str), &'static str> {
+    let mut value = String::new();
+    let mut chars = quoted.chars();
+    loop {
+        match chars.next() {
+            Some('"') => break,
+            Some('\\') => match chars.next() {
+                Some('n') => value.push('\n'),
+                Some('r') => value.push('\r'),
+                Some('t') => value.push('\t'),
+                Some(ch @ ('"' | '\\')) => value.push(ch),
+                _ => return Err("Unsupported escape sequence"),
+            },
+            Some(ch) => value.push(ch),
+            None => return Err("Unterminated string"),
+        }
+    }
+    Ok((value, chars.as_str()))
+}
+
+fn parse_value(line_index: usize, line: &str, raw: &str) -> Result<Value, AocError> {
+    let column = raw.as_ptr() as usize - line.as_ptr() as usize + 1;
+    let error = |message: &str| AocError::parse(line_index, column, message);
+
+    if let Some(quoted) = raw.strip_prefix('"') {
+        let (value, rest) = parse_string(quoted).map_err(error)?;
+        let rest = rest.trim();
+        if !rest.is_empty() && !rest.starts_with('#') {
+            return Err(error("Unexpected text after string"));
+        }
+        Ok(Value::String(value))
+    } else {
+        let raw = raw.split('#').next().unwrap_or("").trim();
+        raw.parse()
+            .map(Value::Integer)
+            .map_err(|_| error("Expected a quoted string or an integer"))
+    }
+}
+
+impl Document {
+    pub fn parse(text: &str) -> Result<Document, AocError> {
+        let mut document = Document::default();
+        let mut section: Option<String> = None;
+
+        for (line_index, line) in text.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = trimmed.strip_prefix('[') {
+                let name = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| AocError::parse(line_index, 1, "Expected ']'"))?
+                    .trim();
+                document.sections.entry(name.to_string()).or_default();
+                section = Some(name.to_string());
+                continue;
+            }
+
+            let expected_pair = || AocError::parse(line_index, 1, "Expected 'key = value'");
+            let (key, raw_value) = match trimmed.strip_prefix('"') {
+                Some(quoted) => {
+                    let (key, rest) = parse_string(quoted)
+                        .map_err(|message| AocError::parse(line_index, 1, message))?;
+                    let raw_value = rest
+                        .trim_start()
+                        .strip_prefix('=')
+                        .ok_or_else(expected_pair)?;
+                    (key, raw_value)
+                }
+                None => {
+                    let (key, raw_value) = trimmed.split_once('=').ok_or_else(expected_pair)?;
+                    (key.trim().to_string(), raw_value)
+                }
+            };
+            let value = parse_value(line_index, line, raw_value.trim())?;
+
+            let table = match &section {
+                Some(name) => document.sections.get_mut(name).unwrap(),
+                None => &mut document.root,
+            };
+            table.insert(key, value);
+        }
+
+        Ok(document)
+    }
+
+    pub fn load(path: &std::path::Path) -> Result<Document, AocError> {
+        Document::parse(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn get(&self, section: Option<&str>, key: &str) -> Option<&Value> {
+        match section {
+            Some(name) => self.sections.get(name)?.get(key),
+            None => self.root.get(key),
+        }
+    }
+}
+
+impl Display for Document {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (key, value) in &self.root {
+            write_key(f, key)?;
+            writeln!(f, " = {}", value)?;
+        }
+        for (index, (name, table)) in self.sections.iter().enumerate() {
+            if index > 0 || !self.root.is_empty() {
+                writeln!(f)?;
+            }
+            writeln!(f, "[{}]", name)?;
+            for (key, value) in table {
+                write_key(f, key)?;
+                writeln!(f, " = {}", value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reads_root_keys_and_sections() {
+        let document = Document::parse(
+            "# comment\nname = \"a \\\"b\\\"\" # trailing\n\n[day_01]\npart_1 = 11\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            document.get(None, "name"),
+            Some(&Value::String("a \"b\"".to_string()))
+        );
+        assert_eq!(
+            document.get(Some("day_01"), "part_1"),
+            Some(&Value::Integer(11))
+        );
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let text = "name = \"x\\ny\"\n\n[a]\nkey = -3\n\n[b]\nkey = \"value\"\n";
+        let document = Document::parse(text).unwrap();
+
+        assert_eq!(document.to_string(), text);
+        assert_eq!(Document::parse(&document.to_string()).unwrap(), document);
+    }
+
+    #[test]
+    fn keys_that_are_not_bare_are_quoted() {
+        let mut document = Document::default();
+        let keys = ["plain_key-1", "a = b", "4,6,3", "say \"hi\"", ""];
+        for key in keys {
+            document
+                .root
+                .insert(key.to_string(), Value::String(key.to_string()));
+        }
+        let text = document.to_string();
+
+        assert!(text.contains("plain_key-1 = \"plain_key-1\"\n"));
+        assert!(text.contains("\"a = b\" = \"a = b\"\n"));
+        assert!(text.contains("\"say \\\"hi\\\"\" = "));
+        assert_eq!(Document::parse(&text).unwrap(), document);
+        assert!(Document::parse("\"a\" 1\n").is_err());
+    }
+
+    #[test]
+    fn parse_reports_line_of_bad_value() {
+        let err = Document::parse("a = 1\nb = nope\n").unwrap_err();
+
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 2,
+                column: 5,
+                ..
+            }
+        ));
+    }
+}
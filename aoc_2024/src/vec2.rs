@@ -0,0 +1,132 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position or offset on a grid, where `x` is the column and `y` the row, growing downwards.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Vec2 {
+        Vec2 { x, y }
+    }
+
+    /// The position of a cell of an `Array2D`.
+    pub fn from_index(row: usize, col: usize) -> Vec2 {
+        Vec2::new(col as i64, row as i64)
+    }
+
+    /// The (row, column) of the position in an `Array2D`, if neither is negative.
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+
+    /// Number of steps between the positions when moving along rows and columns only.
+    pub fn manhattan(self, other: Vec2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Number of steps between the positions when diagonal steps are allowed too.
+    pub fn chebyshev(self, other: Vec2) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+}
+
+/// Row by row, like the cells of an `Array2D`.
+impl Ord for Vec2 {
+    fn cmp(&self, other: &Vec2) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for Vec2 {
+    fn partial_cmp(&self, other: &Vec2) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, factor: i64) -> Vec2 {
+        Vec2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Display for Vec2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_and_distances() {
+        let a = Vec2::new(1, 2);
+        let b = Vec2::new(4, -2);
+
+        assert_eq!(a + b, Vec2::new(5, 0));
+        assert_eq!(a - b, Vec2::new(-3, 4));
+        assert_eq!(-a * 3, Vec2::new(-3, -6));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.to_index(), Some((2, 1)));
+        assert_eq!(b.to_index(), None);
+        assert_eq!(Vec2::from_index(2, 1), a);
+    }
+
+    #[test]
+    fn ordering_is_row_major() {
+        let mut positions = vec![Vec2::new(0, 1), Vec2::new(1, 0), Vec2::new(0, 0)];
+        positions.sort();
+
+        assert_eq!(
+            positions,
+            vec![Vec2::new(0, 0), Vec2::new(1, 0), Vec2::new(0, 1)]
+        );
+    }
+}